@@ -0,0 +1,36 @@
+//! Generates a sample test for each day and part with a recorded answer; see `tests/samples.rs`.
+
+use std::fmt::Write;
+use std::path::Path;
+
+const LAST_DAY: u8 = 25;
+
+fn main() {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut tests = String::new();
+    for day in 1..=LAST_DAY {
+        let day_dir = workspace.join(format!("day{}", day));
+        println!("cargo:rerun-if-changed={}", day_dir.display());
+        if !day_dir.join("sample-input.txt").exists() {
+            continue;
+        }
+        let Ok(answers) = std::fs::read_to_string(day_dir.join("sample-answers.txt")) else {
+            continue;
+        };
+        for (part, variant) in [("1", "One"), ("2", "Two")] {
+            if answers
+                .lines()
+                .any(|line| line.split_once(':').map(|(p, _)| p) == Some(part))
+            {
+                writeln!(
+                    tests,
+                    "#[test]\nfn day{:02}_part{}() -> Result<()> {{\n    check_sample({}, Part::{})\n}}\n",
+                    day, part, day, variant
+                )
+                .unwrap();
+            }
+        }
+    }
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(Path::new(&out_dir).join("sample_tests.rs"), tests).unwrap();
+}
@@ -0,0 +1,142 @@
+//! A counting global allocator, installed by the `alloc-profile` feature.
+//!
+//! The counters are process-wide, so measurements are only meaningful while one thing runs at a
+//! time.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+
+use color_eyre::eyre::Result;
+use common_utils::{Input, Part, Solution};
+
+use crate::bench::Stage;
+
+/// Wraps [`System`], counting every allocation.
+pub struct CountingAllocator;
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size as u64, Relaxed);
+    grow_live(size as u64);
+}
+
+fn grow_live(size: u64) {
+    let live = LIVE_BYTES.fetch_add(size, Relaxed) + size;
+    PEAK_LIVE_BYTES.fetch_max(live, Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE_BYTES.fetch_sub(layout.size() as u64, Relaxed);
+    }
+
+    /// Counts as one allocation of `new_size` bytes, since that's what a realloc may cost.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Relaxed);
+            BYTES.fetch_add(new_size as u64, Relaxed);
+            let old_size = layout.size() as u64;
+            let new_size = new_size as u64;
+            if new_size >= old_size {
+                grow_live(new_size - old_size);
+            } else {
+                LIVE_BYTES.fetch_sub(old_size - new_size, Relaxed);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// What one piece of work allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// The most that was live at once, over what was already live when measuring started.
+    pub peak_live_bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StageAllocs {
+    pub stage: Stage,
+    pub stats: AllocStats,
+}
+
+/// Runs `f`, counting its allocations. Dropping its output isn't counted.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let start_allocations = ALLOCATIONS.load(Relaxed);
+    let start_bytes = BYTES.load(Relaxed);
+    let start_live = LIVE_BYTES.load(Relaxed);
+    PEAK_LIVE_BYTES.store(start_live, Relaxed);
+    let output = f();
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Relaxed) - start_allocations,
+        bytes: BYTES.load(Relaxed) - start_bytes,
+        peak_live_bytes: PEAK_LIVE_BYTES.load(Relaxed).saturating_sub(start_live),
+    };
+    (output, stats)
+}
+
+/// Counts allocations while parsing and then while solving each of `parts`.
+pub fn profile<S: Solution>(input: &Input, parts: &[Part]) -> Result<Vec<StageAllocs>> {
+    let (parsed, parse_stats) = measure(|| S::parse_input(input));
+    let parsed = parsed?;
+    let mut stats = vec![StageAllocs {
+        stage: Stage::Parse,
+        stats: parse_stats,
+    }];
+    for &part in parts {
+        let (answer, part_stats) = measure(|| S::solve(&parsed, part));
+        answer?;
+        stats.push(StageAllocs {
+            stage: Stage::Part(part),
+            stats: part_stats,
+        });
+    }
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Other tests allocate on their own threads meanwhile, so only lower bounds are reliable.
+    #[test]
+    fn counts_allocations_and_peak() {
+        let (_, stats) = measure(|| {
+            let big = vec![0u8; 4096];
+            drop(big);
+            let small = vec![0u8; 16];
+            small
+        });
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 4096 + 16);
+        assert!(stats.peak_live_bytes >= 4096);
+    }
+}
@@ -0,0 +1,263 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use color_eyre::eyre::{bail, eyre, Report, Result, WrapErr};
+use common_utils::{sections, workspace_root, Answer, Input, Part};
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// Known-good answers for real inputs, at the workspace root.
+pub const MANIFEST_FILE: &str = "answers.txt";
+
+/// Parses a list of answers, as used by the manifest and `dayN/sample-answers.txt`.
+///
+/// Each answer starts on a `1:` or `2:` line. Lines after it, up to the next part, are further
+/// lines of the same answer, so ASCII-art answers are written out as they print.
+pub fn parse_answers(text: &str) -> Result<Vec<(Part, String)>> {
+    let mut answers: Vec<(Part, String)> = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let header = line.split_once(':').and_then(|(part, rest)| match part {
+            "1" => Some((Part::One, rest.trim())),
+            "2" => Some((Part::Two, rest.trim())),
+            _ => None,
+        });
+        match (header, answers.last_mut()) {
+            (Some((part, rest)), _) => answers.push((part, rest.to_owned())),
+            (None, Some((_, answer))) => {
+                answer.push('\n');
+                answer.push_str(line);
+            }
+            (None, None) => bail!("Line {}: expected `1:` or `2:`, got {:?}", i + 1, line),
+        }
+    }
+    Ok(answers)
+}
+
+/// The hex SHA-256 of an input's contents, which is how the manifest tells inputs apart.
+pub fn input_hash(input: &Input) -> String {
+    Sha256::digest(input.as_str().as_bytes()).iter().fold(
+        String::with_capacity(64),
+        |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+            hex
+        },
+    )
+}
+
+/// One solved part, for `aoc run --format json`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AnswerRecord {
+    pub day: u8,
+    pub part: u8,
+    /// A number, a string, or an array of rows for answers drawn as art.
+    pub answer: Value,
+    /// `int`, `text` or `art`; absent if the part failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer_type: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub duration_ns: u64,
+    /// See [`input_hash`].
+    pub input_hash: String,
+}
+
+impl AnswerRecord {
+    pub fn new(
+        day: u8,
+        part: Part,
+        answer: &Result<Answer, Report>,
+        elapsed: Duration,
+        input_hash: &str,
+    ) -> Self {
+        let (answer, answer_type, error) = match answer {
+            Ok(Answer::Int(x)) => (int_value(*x), Some("int"), None),
+            Ok(Answer::Text(s)) => (Value::from(s.as_str()), Some("text"), None),
+            Ok(Answer::Art(rows)) => (Value::from(rows.clone()), Some("art"), None),
+            Err(e) => (Value::Null, None, Some(format!("{:#}", e))),
+        };
+        Self {
+            day,
+            part: match part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            answer,
+            answer_type,
+            error,
+            duration_ns: elapsed.as_nanos().try_into().unwrap_or(u64::MAX),
+            input_hash: input_hash.to_owned(),
+        }
+    }
+}
+
+/// A JSON number if it fits in 64 bits, otherwise the digits as a string so nothing is lost.
+fn int_value(x: i128) -> Value {
+    i64::try_from(x)
+        .map(Value::from)
+        .or_else(|_| u64::try_from(x).map(Value::from))
+        .unwrap_or_else(|_| Value::from(x.to_string()))
+}
+
+/// How a fresh answer compares with the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch {
+        expected: String,
+    },
+    /// Nothing is recorded for this day, part and input.
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Verdict::Match => "match",
+            Verdict::Mismatch { .. } => "MISMATCH",
+            Verdict::Unknown => "unknown",
+        })
+    }
+}
+
+/// Recorded answers, keyed by day and input hash.
+///
+/// On disk, each input gets a blank-line-separated block headed `day N <hash>`, followed by its
+/// answers in the [`parse_answers`] format.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    entries: BTreeMap<(u8, String), BTreeMap<Part, String>>,
+}
+
+impl Manifest {
+    pub fn path() -> PathBuf {
+        workspace_root().join(MANIFEST_FILE)
+    }
+
+    /// Reads the manifest, treating a missing file as an empty one.
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text).wrap_err_with(|| format!("In {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).wrap_err_with(|| format!("Couldn't read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        std::fs::write(&path, self.to_string())
+            .wrap_err_with(|| format!("Couldn't write {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut manifest = Self::default();
+        for section in sections(text) {
+            let (header, answers) = section.text.split_once('\n').unwrap_or((section.text, ""));
+            let key = header
+                .trim_end()
+                .strip_prefix("day ")
+                .and_then(|rest| rest.split_once(' '))
+                .and_then(|(day, hash)| Some((day.parse().ok()?, hash.to_owned())))
+                .ok_or_else(|| {
+                    eyre!(
+                        "Line {}: expected `day N <hash>`, got {:?}",
+                        section.first_line,
+                        header
+                    )
+                })?;
+            let answers = parse_answers(answers)
+                .wrap_err_with(|| format!("In the block on line {}", section.first_line))?;
+            manifest.entries.entry(key).or_default().extend(answers);
+        }
+        Ok(manifest)
+    }
+
+    pub fn expected(&self, day: u8, hash: &str, part: Part) -> Option<&str> {
+        self.entries
+            .get(&(day, hash.to_owned()))
+            .and_then(|answers| answers.get(&part))
+            .map(String::as_str)
+    }
+
+    pub fn verdict(&self, day: u8, hash: &str, part: Part, answer: &str) -> Verdict {
+        match self.expected(day, hash, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Match,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+
+    pub fn record(&mut self, day: u8, hash: &str, part: Part, answer: String) {
+        self.entries
+            .entry((day, hash.to_owned()))
+            .or_default()
+            .insert(part, answer);
+    }
+}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, ((day, hash), answers)) in self.entries.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "day {} {}", day, hash)?;
+            for (part, answer) in answers {
+                // Multi-line answers already start with the newline that ends the header.
+                if answer.starts_with('\n') {
+                    writeln!(f, "{}:{}", part, answer)?;
+                } else {
+                    writeln!(f, "{}: {}", part, answer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_line_answers() {
+        let answers = parse_answers("1: 42\n2:\n#.#\n.#.\n").unwrap();
+        assert_eq!(
+            answers,
+            [
+                (Part::One, "42".to_owned()),
+                (Part::Two, "\n#.#\n.#.".to_owned())
+            ]
+        );
+        assert!(parse_answers("oops\n1: 2").is_err());
+    }
+
+    #[test]
+    fn answer_records_as_json() {
+        let art = Ok(Answer::Art(vec!["#.".to_owned(), ".#".to_owned()]));
+        let record = AnswerRecord::new(10, Part::Two, &art, Duration::from_micros(3), "abc");
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r##"{"day":10,"part":2,"answer":["#.",".#"],"answer_type":"art","duration_ns":3000,"input_hash":"abc"}"##
+        );
+        let failed = AnswerRecord::new(1, Part::One, &Err(eyre!("boom")), Duration::ZERO, "abc");
+        assert_eq!(failed.answer, Value::Null);
+        assert_eq!(failed.error.as_deref(), Some("boom"));
+    }
+
+    #[test]
+    fn manifest_round_trips() {
+        let mut manifest = Manifest::default();
+        manifest.record(10, "abc", Part::Two, "\n#.\n.#".to_owned());
+        manifest.record(1, "def", Part::One, "7".to_owned());
+        let reparsed = Manifest::parse(&manifest.to_string()).unwrap();
+        assert_eq!(reparsed, manifest);
+        assert_eq!(reparsed.verdict(1, "def", Part::One, "7"), Verdict::Match);
+        assert_eq!(reparsed.verdict(1, "xyz", Part::One, "7"), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use color_eyre::eyre::Result;
+use common_utils::{AocResult, Input, InputKind, Part, Solution};
+use serde::{Deserialize, Serialize};
+
+/// One separately timed piece of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part(part) => f.pad(&format!("part{}", part)),
+        }
+    }
+}
+
+/// How many times to run each stage.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Untimed runs first, to warm caches and the allocator.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+/// Summary of the timed iterations of one stage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Sample standard deviation; zero for a single iteration.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarizes `samples`, which must not be empty.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        samples.sort_unstable();
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        let mean_secs = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            samples
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean_secs).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Self {
+            iterations: n,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StageTiming {
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Times parsing and then each of `parts`, reusing one parse for the parts.
+///
+/// Input is already in memory, so file I/O isn't part of any timing.
+pub fn bench<S: Solution>(
+    input: &Input,
+    parts: &[Part],
+    config: &BenchConfig,
+) -> Result<Vec<StageTiming>> {
+    let mut timings = vec![StageTiming {
+        stage: Stage::Parse,
+        stats: time_stage(config, || S::parse_input(input))?,
+    }];
+    let parsed = S::parse_input(input)?;
+    for &part in parts {
+        let stats = time_stage(config, || S::solve(&parsed, part))?;
+        timings.push(StageTiming {
+            stage: Stage::Part(part),
+            stats,
+        });
+    }
+    Ok(timings)
+}
+
+fn time_stage<T>(config: &BenchConfig, mut stage: impl FnMut() -> AocResult<T>) -> Result<Stats> {
+    for _ in 0..config.warmup {
+        black_box(stage()?);
+    }
+    let mut samples = Vec::with_capacity(config.iterations);
+    for _ in 0..config.iterations.max(1) {
+        let start = Instant::now();
+        let output = stage()?;
+        samples.push(start.elapsed());
+        black_box(output);
+    }
+    Ok(Stats::from_samples(&mut samples))
+}
+
+/// A flattened [`StageTiming`] for JSON output, with times in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchRecord {
+    pub day: u8,
+    /// `real` or `sample`.
+    pub input: String,
+    /// `parse`, `part1` or `part2`.
+    pub stage: String,
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl BenchRecord {
+    pub fn new(day: u8, kind: InputKind, timing: &StageTiming) -> Self {
+        let nanos = |duration: Duration| duration.as_nanos().try_into().unwrap_or(u64::MAX);
+        Self {
+            day,
+            input: kind.name().to_owned(),
+            stage: timing.stage.to_string(),
+            iterations: timing.stats.iterations,
+            min_ns: nanos(timing.stats.min),
+            median_ns: nanos(timing.stats.median),
+            mean_ns: nanos(timing.stats.mean),
+            stddev_ns: nanos(timing.stats.stddev),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_samples() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        // Sample variance of 1, 2, 3, 4 is 5/3.
+        assert!((stats.stddev.as_secs_f64() * 1000.0 - (5.0f64 / 3.0).sqrt()).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,241 @@
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use common_utils::workspace_root;
+
+pub const YEAR: u16 = 2022;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Identifies us to the site, as its maintainer asks automated tools to do.
+pub const USER_AGENT: &str = concat!(
+    "github.com/khoover/advent-of-code-2022 aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+/// Environment variable holding the `session` cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// The shortest gap between two requests from one [`Fetcher`].
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Where fetched inputs are kept, at the workspace root. Inputs are per-account, so it isn't
+/// checked in.
+pub fn default_cache_dir() -> PathBuf {
+    workspace_root().join(".aoc-cache")
+}
+
+/// The `session` cookie from `$AOC_SESSION`, or else the first line of
+/// `$XDG_CONFIG_HOME/aoc/session` (`~/.config/aoc/session` by default).
+pub fn session_token() -> Result<String> {
+    if let Ok(token) = std::env::var(SESSION_ENV) {
+        if !token.trim().is_empty() {
+            return Ok(token.trim().to_owned());
+        }
+    }
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .ok_or_else(|| {
+            eyre!(
+                "${} is not set and there's no config directory",
+                SESSION_ENV
+            )
+        })?;
+    let path = config_dir.join("aoc").join("session");
+    let contents = std::fs::read_to_string(&path).wrap_err_with(|| {
+        format!(
+            "${} is not set and {} couldn't be read",
+            SESSION_ENV,
+            path.display()
+        )
+    })?;
+    contents
+        .lines()
+        .next()
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(str::to_owned)
+        .ok_or_else(|| eyre!("{} is empty", path.display()))
+}
+
+/// An HTTP client that identifies itself with [`USER_AGENT`].
+pub(crate) fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .user_agent(USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .build()
+}
+
+/// Downloads puzzle inputs, keeping each one so it's only ever requested once.
+pub struct Fetcher {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    pub fn new(session: String, cache_dir: PathBuf) -> Self {
+        Self {
+            agent: agent(),
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            session,
+            cache_dir,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    /// Points the fetcher at another server, e.g. a local stand-in for tests.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn cached_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day{}.txt", day))
+    }
+
+    /// The day's input, from the cache if it's there and from the site otherwise.
+    pub fn input(&mut self, day: u8) -> Result<String> {
+        let path = self.cached_path(day);
+        if path.exists() {
+            return std::fs::read_to_string(&path)
+                .wrap_err_with(|| format!("Couldn't read cached input {}", path.display()));
+        }
+        let input = self.download(day)?;
+        std::fs::create_dir_all(&self.cache_dir)
+            .wrap_err_with(|| format!("Couldn't create {}", self.cache_dir.display()))?;
+        // Write then rename, so an interrupted fetch never leaves a truncated input cached.
+        let partial = path.with_extension("part");
+        std::fs::write(&partial, &input)
+            .and_then(|_| std::fs::rename(&partial, &path))
+            .wrap_err_with(|| format!("Couldn't cache input at {}", path.display()))?;
+        Ok(input)
+    }
+
+    fn download(&mut self, day: u8) -> Result<String> {
+        if let Some(last) = self.last_request {
+            if let Some(wait) = self.min_interval.checked_sub(last.elapsed()) {
+                thread::sleep(wait);
+            }
+        }
+        self.last_request = Some(Instant::now());
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        match response {
+            Ok(response) => response
+                .into_string()
+                .wrap_err_with(|| format!("Couldn't read the response from {}", url)),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(eyre!(
+                    "{} answered {}: {}",
+                    url,
+                    status,
+                    body.lines().next().unwrap_or("").trim()
+                ))
+            }
+            Err(e) => Err(e).wrap_err_with(|| format!("Couldn't fetch {}", url)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    /// Serves `input` to requests carrying the session `secret`, counting requests.
+    fn mock_site(input: &'static str) -> (String, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let header = |name: &'static str| {
+                    request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.equiv(name))
+                        .map(|header| header.value.to_string())
+                };
+                let response = if header("Cookie").as_deref() != Some("session=secret") {
+                    Response::from_string("Puzzle inputs differ by user.").with_status_code(400)
+                } else if header("User-Agent").as_deref() != Some(USER_AGENT) {
+                    Response::from_string("Who are you?").with_status_code(403)
+                } else if request.url() != "/2022/day/3/input"
+                    && request.url() != "/2022/day/4/input"
+                {
+                    Response::from_string("Not found").with_status_code(404)
+                } else {
+                    Response::from_string(input)
+                };
+                let _ = request.respond(response);
+            }
+        });
+        (base_url, requests)
+    }
+
+    fn temp_cache(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_once_then_uses_cache() {
+        let (base_url, requests) = mock_site("1\n2\n");
+        let cache = temp_cache("cache");
+        for _ in 0..2 {
+            let mut fetcher =
+                Fetcher::new("secret".to_owned(), cache.clone()).with_base_url(base_url.clone());
+            assert_eq!(fetcher.input(3).unwrap(), "1\n2\n");
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert!(cache.join("day3.txt").exists());
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn reports_rejections_without_caching() {
+        let (base_url, _) = mock_site("1\n");
+        let cache = temp_cache("rejected");
+        let mut fetcher = Fetcher::new("wrong".to_owned(), cache.clone()).with_base_url(base_url);
+        let err = fetcher.input(3).unwrap_err();
+        assert!(format!("{}", err).contains("400"));
+        assert!(!cache.join("day3.txt").exists());
+    }
+
+    #[test]
+    fn spaces_out_requests() {
+        let (base_url, requests) = mock_site("1\n");
+        let cache = temp_cache("rate");
+        let mut fetcher = Fetcher::new("secret".to_owned(), cache.clone())
+            .with_base_url(base_url)
+            .with_min_interval(Duration::from_millis(200));
+        let start = Instant::now();
+        fetcher.input(3).unwrap();
+        fetcher.input(4).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+}
@@ -0,0 +1,216 @@
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use color_eyre::eyre::{ensure, Result, WrapErr};
+use common_utils::workspace_root;
+use serde::{Deserialize, Serialize};
+
+use crate::bench::BenchRecord;
+
+/// Saved benchmark runs, at the workspace root. Timings are machine-specific, so it isn't
+/// checked in.
+pub const HISTORY_FILE: &str = "bench-history.json";
+
+/// One saved `aoc bench` invocation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchRun {
+    /// `HEAD` when the run was made.
+    pub commit: String,
+    /// Whether the working tree had uncommitted changes, so `commit` isn't the whole story.
+    pub dirty: bool,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// A name to compare against later with `aoc compare --against`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<String>,
+    pub records: Vec<BenchRecord>,
+}
+
+impl BenchRun {
+    /// Stamps `records` with the current commit and time.
+    pub fn new(records: Vec<BenchRecord>, baseline: Option<String>) -> Result<Self> {
+        let (commit, dirty) = current_commit()?;
+        Ok(Self {
+            commit,
+            dirty,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            baseline,
+            records,
+        })
+    }
+
+    /// A short description for report headers, e.g. `1a2b3c4d+ (baseline "before-sort")`.
+    pub fn describe(&self) -> String {
+        let mut description: String = self.commit.chars().take(8).collect();
+        if self.dirty {
+            description.push('+');
+        }
+        if let Some(baseline) = &self.baseline {
+            description.push_str(&format!(" (baseline {:?})", baseline));
+        }
+        description
+    }
+}
+
+fn current_commit() -> Result<(String, bool)> {
+    let git = |args: &[&str]| -> Result<String> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(workspace_root())
+            .output()
+            .wrap_err("Couldn't run git")?;
+        ensure!(
+            output.status.success(),
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+    let commit = git(&["rev-parse", "HEAD"])?;
+    let dirty = !git(&["status", "--porcelain", "--untracked-files=no"])?.is_empty();
+    Ok((commit, dirty))
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    /// Oldest first.
+    pub runs: Vec<BenchRun>,
+}
+
+impl History {
+    pub fn path() -> PathBuf {
+        workspace_root().join(HISTORY_FILE)
+    }
+
+    /// Reads the history, treating a missing file as an empty one.
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        match std::fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text)
+                .wrap_err_with(|| format!("Couldn't parse {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).wrap_err_with(|| format!("Couldn't read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)
+            .wrap_err_with(|| format!("Couldn't write {}", path.display()))
+    }
+
+    pub fn latest(&self) -> Option<&BenchRun> {
+        self.runs.last()
+    }
+
+    /// The run to compare the latest one against: the most recent earlier run with baseline
+    /// name `against` or a commit starting with it, or just the previous run.
+    pub fn reference(&self, against: Option<&str>) -> Option<&BenchRun> {
+        let earlier = &self.runs[..self.runs.len().saturating_sub(1)];
+        match against {
+            None => earlier.last(),
+            Some(name) => earlier
+                .iter()
+                .rev()
+                .find(|run| run.baseline.as_deref() == Some(name) || run.commit.starts_with(name)),
+        }
+    }
+}
+
+/// How one stage's median time changed between two runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub input: String,
+    pub stage: String,
+    pub before_ns: u64,
+    pub after_ns: u64,
+}
+
+impl Comparison {
+    /// Positive when `after` is slower.
+    pub fn change_percent(&self) -> f64 {
+        if self.before_ns == 0 {
+            return 0.0;
+        }
+        (self.after_ns as f64 - self.before_ns as f64) / self.before_ns as f64 * 100.0
+    }
+
+    pub fn regressed(&self, threshold_percent: f64) -> bool {
+        self.change_percent() > threshold_percent
+    }
+}
+
+/// Pairs up the stages timed in both runs, in `current`'s order.
+pub fn compare(reference: &BenchRun, current: &BenchRun) -> Vec<Comparison> {
+    current
+        .records
+        .iter()
+        .filter_map(|after| {
+            let before = reference.records.iter().find(|before| {
+                (before.day, &before.input, &before.stage)
+                    == (after.day, &after.input, &after.stage)
+            })?;
+            Some(Comparison {
+                day: after.day,
+                input: after.input.clone(),
+                stage: after.stage.clone(),
+                before_ns: before.median_ns,
+                after_ns: after.median_ns,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(commit: &str, baseline: Option<&str>, part1_ns: u64) -> BenchRun {
+        let record = |stage: &str, median_ns| BenchRecord {
+            day: 8,
+            input: "real".to_owned(),
+            stage: stage.to_owned(),
+            iterations: 1,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            stddev_ns: 0,
+        };
+        BenchRun {
+            commit: commit.to_owned(),
+            dirty: false,
+            timestamp: 0,
+            baseline: baseline.map(str::to_owned),
+            records: vec![record("parse", 100), record("part1", part1_ns)],
+        }
+    }
+
+    #[test]
+    fn picks_reference_runs() {
+        let history = History {
+            runs: vec![
+                run("aaaa", Some("fast"), 100),
+                run("bbbb", None, 100),
+                run("cccc", Some("fast"), 100),
+            ],
+        };
+        assert_eq!(history.reference(None).unwrap().commit, "bbbb");
+        assert_eq!(history.reference(Some("fast")).unwrap().commit, "aaaa");
+        assert_eq!(history.reference(Some("bb")).unwrap().commit, "bbbb");
+        assert!(history.reference(Some("cccc")).is_none());
+    }
+
+    #[test]
+    fn flags_regressions_over_threshold() {
+        let comparisons = compare(&run("a", None, 100), &run("b", None, 125));
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed(10.0));
+        assert!(comparisons[1].regressed(10.0));
+        assert!(!comparisons[1].regressed(30.0));
+    }
+}
@@ -0,0 +1,15 @@
+//! The pieces of the runner that tests need too: the table of solved days, recorded answers and
+//! the sample checks.
+
+#[cfg(feature = "alloc-profile")]
+pub mod alloc_profile;
+pub mod answers;
+pub mod bench;
+pub mod fetch;
+pub mod history;
+pub mod parallel;
+pub mod registry;
+pub mod samples;
+pub mod submit;
+pub mod trace;
+pub mod watch;
@@ -0,0 +1,991 @@
+use std::io::IsTerminal;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use color_eyre::eyre::{bail, eyre, Report, Result};
+use common_utils::{day_dir, Answer, ImageFormat, InputKind, InputSource, Part, Playback};
+
+#[cfg(feature = "alloc-profile")]
+use aoc::alloc_profile::StageAllocs;
+use aoc::answers::{input_hash, AnswerRecord, Manifest, Verdict};
+use aoc::bench::{BenchConfig, BenchRecord};
+use aoc::fetch::{self, Fetcher};
+use aoc::history::{self, BenchRun, History};
+use aoc::parallel::{self, PoolConfig, TaskOutcome};
+use aoc::registry::{self, Entry, PartOutcome, LAST_DAY};
+use aoc::submit::{self, Submitter, Verdict as Submission};
+use aoc::watch::{self, DayWatcher, PartChange, WatchConfig};
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log the solvers' spans and events to stderr, keeping those matching FILTER (same syntax
+    /// as `RUST_LOG`, which is used if this isn't given; `debug` by default).
+    #[arg(
+        long,
+        global = true,
+        value_name = "FILTER",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = aoc::trace::DEFAULT_FILTER
+    )]
+    trace: Option<String>,
+    /// Write the trace to this file as JSON lines instead of to stderr.
+    #[arg(long, global = true, value_name = "FILE")]
+    trace_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one day, or every implemented day in order.
+    Run(RunArgs),
+    /// Re-solve real inputs and compare the answers with `answers.txt`.
+    Verify(VerifyArgs),
+    /// Time parsing and each part over many iterations.
+    Bench(BenchArgs),
+    /// Compare the latest saved benchmark run with an earlier one.
+    Compare(CompareArgs),
+    /// Count allocations while parsing and solving.
+    #[cfg(feature = "alloc-profile")]
+    Alloc(AllocArgs),
+    /// Download puzzle inputs into `dayN/input.txt`, using the session cookie.
+    Fetch(FetchArgs),
+    /// Send an answer to the site, unless an earlier guess already rules it out.
+    Submit(SubmitArgs),
+    /// Print a random input for a day, to try its solution on more than the real input.
+    Gen(GenArgs),
+    /// Re-build and re-run a day whenever its source or input changes, showing what changed.
+    Watch(WatchArgs),
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// Day to run (1-25), or `all`.
+    day: DaySelection,
+    /// Which part(s) to solve.
+    #[arg(long, value_enum, default_value_t = PartSelection::Both)]
+    part: PartSelection,
+    /// Read the puzzle input from this file (or `-` for stdin) instead of `dayN/input.txt`.
+    #[arg(long, conflicts_with = "sample", value_parser = parse_input_source)]
+    input: Option<InputSource>,
+    /// Use `dayN/sample-input.txt` instead of `dayN/input.txt`.
+    #[arg(long)]
+    sample: bool,
+    /// How to print the answers.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+    /// Watch the simulation in the terminal before printing the answers.
+    #[arg(long, conflicts_with = "format")]
+    visualize: bool,
+    /// Save a picture of each part's final state to FILE (.png or .ppm), or an animation of how
+    /// it got there (.gif). With both parts, `-part1` and `-part2` go before the extension.
+    #[arg(long, value_name = "FILE")]
+    render: Option<PathBuf>,
+    /// With `all`, how many days to run at once. Defaults to the number of cores; timings are
+    /// only comparable to `bench` with `--jobs 1`.
+    #[arg(long)]
+    jobs: Option<NonZeroUsize>,
+    /// With `all`, give up on a day that is still running after this many seconds.
+    #[arg(long, default_value_t = 60.0)]
+    timeout: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Table,
+    /// A JSON array with one object per part.
+    Json,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// Day to verify (1-25), or `all`.
+    #[arg(default_value = "all")]
+    day: DaySelection,
+    /// Add answers for inputs that aren't in the manifest yet.
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+    /// Day to benchmark (1-25), or `all`.
+    day: DaySelection,
+    /// Which part(s) to time; parsing is always timed.
+    #[arg(long, value_enum, default_value_t = PartSelection::Both)]
+    part: PartSelection,
+    /// Which checked-in inputs to time against.
+    #[arg(long, value_enum, default_value_t = InputSelection::Real)]
+    inputs: InputSelection,
+    /// Timed iterations per stage.
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+    /// Untimed iterations per stage before timing starts.
+    #[arg(long, default_value_t = 3)]
+    warmup: u32,
+    /// Print the results as a JSON array instead of a table.
+    #[arg(long)]
+    json: bool,
+    /// Append the results to `bench-history.json`, tagged with the current commit.
+    #[arg(long)]
+    save: bool,
+    /// Save the results as a named baseline for `aoc compare --against`.
+    #[arg(long, value_name = "NAME")]
+    baseline: Option<String>,
+}
+
+#[derive(Debug, Args)]
+struct CompareArgs {
+    /// Baseline name or commit prefix to compare against, instead of the previous run.
+    #[arg(long, value_name = "NAME")]
+    against: Option<String>,
+    /// Flag stages whose median got slower by more than this many percent.
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[cfg(feature = "alloc-profile")]
+#[derive(Debug, Args)]
+struct AllocArgs {
+    /// Day to profile (1-25), or `all`.
+    day: DaySelection,
+    /// Which part(s) to profile; parsing is always profiled.
+    #[arg(long, value_enum, default_value_t = PartSelection::Both)]
+    part: PartSelection,
+    /// Use `dayN/sample-input.txt` instead of `dayN/input.txt`.
+    #[arg(long)]
+    sample: bool,
+}
+
+#[derive(Debug, Args)]
+struct FetchArgs {
+    /// Day to fetch (1-25), or `all`.
+    day: DaySelection,
+    /// Overwrite `dayN/input.txt` if it's already there.
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Debug, Args)]
+struct SubmitArgs {
+    /// Day the answer is for (1-25).
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=LAST_DAY as i64))]
+    day: u8,
+    /// Part the answer is for.
+    #[arg(value_enum)]
+    part: SinglePart,
+    /// The answer; solves `dayN/input.txt` if left out.
+    answer: Option<String>,
+}
+
+#[derive(Debug, Args)]
+struct GenArgs {
+    /// Day to generate an input for (1-25).
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=LAST_DAY as i64))]
+    day: u8,
+    /// Seed for the generator; the same seed and size always give the same input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// How big to make the input, in the day's own unit (elves, moves, monkeys, ...). Defaults
+    /// to about the size of a real input.
+    #[arg(long)]
+    size: Option<usize>,
+}
+
+#[derive(Debug, Args)]
+struct WatchArgs {
+    /// Day to watch (1-25).
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=LAST_DAY as i64))]
+    day: u8,
+    /// Read the puzzle input from this file instead of `dayN/input.txt`.
+    #[arg(long, conflicts_with = "sample")]
+    input: Option<PathBuf>,
+    /// Use `dayN/sample-input.txt` instead of `dayN/input.txt`.
+    #[arg(long)]
+    sample: bool,
+    /// Build with optimizations, so the timings are worth comparing.
+    #[arg(long)]
+    release: bool,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum SinglePart {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl From<SinglePart> for Part {
+    fn from(part: SinglePart) -> Self {
+        match part {
+            SinglePart::One => Part::One,
+            SinglePart::Two => Part::Two,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum InputSelection {
+    Real,
+    Sample,
+    Both,
+}
+
+impl InputSelection {
+    fn kinds(self) -> &'static [InputKind] {
+        match self {
+            InputSelection::Real => &[InputKind::Real],
+            InputSelection::Sample => &[InputKind::Sample],
+            InputSelection::Both => &[InputKind::Real, InputKind::Sample],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+fn parse_input_source(arg: &str) -> Result<InputSource, std::convert::Infallible> {
+    Ok(InputSource::from_arg(arg))
+}
+
+impl FromStr for DaySelection {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(DaySelection::All);
+        }
+        match s.parse::<u8>() {
+            Ok(day) if (1..=LAST_DAY).contains(&day) => Ok(DaySelection::Day(day)),
+            _ => Err(eyre!(
+                "Expected a day from 1 to {} or `all`, got {}",
+                LAST_DAY,
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum PartSelection {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl PartSelection {
+    fn parts(self) -> &'static [Part] {
+        match self {
+            PartSelection::One => &[Part::One],
+            PartSelection::Two => &[Part::Two],
+            PartSelection::Both => &Part::BOTH,
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse();
+    aoc::trace::init(cli.trace.as_deref(), cli.trace_file.as_deref())?;
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::Compare(args) => compare(args),
+        #[cfg(feature = "alloc-profile")]
+        Command::Alloc(args) => alloc(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::Gen(args) => gen(args),
+        Command::Watch(args) => watch(args),
+    }
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let parts = args.part.parts();
+    // With JSON output, answers are collected and printed together at the end.
+    let mut records = (args.format == OutputFormat::Json).then(Vec::new);
+    let result = match args.day {
+        DaySelection::Day(day) => {
+            let entry =
+                registry::entry(day).ok_or_else(|| eyre!("Day {} is not implemented yet", day))?;
+            let input = args
+                .input
+                .unwrap_or_else(|| InputSource::for_day(day, input_kind(args.sample)));
+            if args.visualize {
+                visualize(entry, &input, parts)?;
+            }
+            if let Some(path) = &args.render {
+                render(entry, &input, parts, path)?;
+            }
+            if records.is_none() {
+                print_header();
+            }
+            run_day(entry, &input, parts, records.as_mut())
+        }
+        DaySelection::All => {
+            if args.input.is_some() {
+                bail!("--input can only be used when running a single day");
+            }
+            if args.visualize {
+                bail!("--visualize can only be used when running a single day");
+            }
+            if args.render.is_some() {
+                bail!("--render can only be used when running a single day");
+            }
+            if records.is_none() {
+                print_header();
+            }
+            let timeout = Duration::try_from_secs_f64(args.timeout)
+                .map_err(|_| eyre!("--timeout must be a non-negative number of seconds"))?;
+            let jobs = args
+                .jobs
+                .or_else(|| std::thread::available_parallelism().ok())
+                .map_or(1, NonZeroUsize::get);
+            let mut stubs = Vec::new();
+            let mut tasks = Vec::new();
+            for day in 1..=LAST_DAY {
+                match registry::entry(day) {
+                    Some(entry) => {
+                        let input = InputSource::for_day(day, input_kind(args.sample));
+                        tasks.push((day, move || solve_day(entry, &input, parts)));
+                    }
+                    None => stubs.push(day),
+                }
+            }
+            let mut passed = Vec::new();
+            let mut failed = Vec::new();
+            let mut timed_out = Vec::new();
+            parallel::run_isolated(tasks, PoolConfig { jobs, timeout }, |day, outcome| {
+                let error = match outcome {
+                    TaskOutcome::Finished(Ok(solved)) => {
+                        match report_day(day, solved, records.as_mut()) {
+                            Ok(()) => {
+                                passed.push(day);
+                                return;
+                            }
+                            Err(e) => {
+                                failed.push(day);
+                                // The failing parts already have their own rows.
+                                if records.is_none() {
+                                    return;
+                                }
+                                e
+                            }
+                        }
+                    }
+                    TaskOutcome::Finished(Err(e)) => {
+                        failed.push(day);
+                        e
+                    }
+                    TaskOutcome::Panicked(message) => {
+                        failed.push(day);
+                        eyre!("panicked: {}", message)
+                    }
+                    TaskOutcome::TimedOut => {
+                        timed_out.push(day);
+                        eyre!("timed out after {:.2?}", timeout)
+                    }
+                };
+                if records.is_some() {
+                    eprintln!("Day {}: {:#}", day, error);
+                } else {
+                    print_row(day, None, None, &format!("error: {:#}", error));
+                }
+            });
+            let summary = format!(
+                "{} passed, {} failed, {} timed out",
+                passed.len(),
+                failed.len(),
+                timed_out.len()
+            );
+            if records.is_none() {
+                println!("\n{}", summary);
+                if !stubs.is_empty() {
+                    println!("Still stubs: {}", join_days(&stubs));
+                }
+            } else {
+                eprintln!("{}", summary);
+            }
+            let mut problems = Vec::new();
+            if !failed.is_empty() {
+                problems.push(format!("Failed days: {}", join_days(&failed)));
+            }
+            if !timed_out.is_empty() {
+                problems.push(format!("Timed out days: {}", join_days(&timed_out)));
+            }
+            if problems.is_empty() {
+                Ok(())
+            } else {
+                Err(eyre!("{}", problems.join("; ")))
+            }
+        }
+    };
+    if let Some(records) = records {
+        println!("{}", serde_json::to_string_pretty(&records)?);
+    }
+    result
+}
+
+fn verify(args: VerifyArgs) -> Result<()> {
+    let days = match args.day {
+        DaySelection::Day(day) => {
+            registry::entry(day).ok_or_else(|| eyre!("Day {} is not implemented yet", day))?;
+            vec![day]
+        }
+        DaySelection::All => (1..=LAST_DAY).collect(),
+    };
+    let mut manifest = Manifest::load()?;
+    let mut failed = Vec::new();
+    let mut recorded = 0;
+    print_header();
+    for entry in days.into_iter().filter_map(registry::entry) {
+        let source = InputSource::for_day(entry.day, InputKind::Real);
+        let outcomes = source
+            .read()
+            .map_err(Report::from)
+            .and_then(|input| Ok((input_hash(&input), entry.solve(&input, &Part::BOTH)?)));
+        let (hash, outcomes) = match outcomes {
+            Ok(solved) => solved,
+            Err(e) => {
+                print_row(entry.day, None, None, &format!("error: {:#}", e));
+                failed.push(entry.day);
+                continue;
+            }
+        };
+        let mut day_failed = false;
+        for outcome in outcomes {
+            let answer = match outcome.answer {
+                Ok(answer) => answer.to_string(),
+                Err(e) => {
+                    day_failed = true;
+                    print_row(
+                        entry.day,
+                        Some(outcome.part),
+                        Some(outcome.elapsed),
+                        &format!("error: {:#}", e),
+                    );
+                    continue;
+                }
+            };
+            let verdict = manifest.verdict(entry.day, &hash, outcome.part, &answer);
+            let status = match &verdict {
+                Verdict::Mismatch { expected } => {
+                    day_failed = true;
+                    format!("{:<8}  {}  (expected {})", verdict, answer, expected)
+                }
+                Verdict::Unknown if args.record => {
+                    manifest.record(entry.day, &hash, outcome.part, answer.clone());
+                    recorded += 1;
+                    format!("{:<8}  {}", "recorded", answer)
+                }
+                _ => format!("{:<8}  {}", verdict, answer),
+            };
+            print_row(
+                entry.day,
+                Some(outcome.part),
+                Some(outcome.elapsed),
+                &indent_continuation(&status),
+            );
+        }
+        if day_failed {
+            failed.push(entry.day);
+        }
+    }
+    if recorded > 0 {
+        manifest.save()?;
+        println!(
+            "\nRecorded {} answer(s) in {}",
+            recorded,
+            Manifest::path().display()
+        );
+    }
+    if !failed.is_empty() {
+        bail!("Failed days: {}", join_days(&failed));
+    }
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<()> {
+    let days = match args.day {
+        DaySelection::Day(day) => {
+            registry::entry(day).ok_or_else(|| eyre!("Day {} is not implemented yet", day))?;
+            vec![day]
+        }
+        DaySelection::All => (1..=LAST_DAY).collect(),
+    };
+    let config = BenchConfig {
+        warmup: args.warmup as usize,
+        iterations: args.iterations as usize,
+    };
+    let mut records = Vec::new();
+    let mut failed = Vec::new();
+    if !args.json {
+        println!(
+            "{:>3}  {:<6}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}",
+            "Day", "Input", "Stage", "Min", "Median", "Mean", "Stddev"
+        );
+    }
+    for entry in days.into_iter().filter_map(registry::entry) {
+        for &kind in args.inputs.kinds() {
+            let timings = InputSource::for_day(entry.day, kind)
+                .read()
+                .map_err(Into::into)
+                .and_then(|input| entry.bench(&input, args.part.parts(), &config));
+            let timings = match timings {
+                Ok(timings) => timings,
+                Err(e) => {
+                    eprintln!("Day {} ({}): {:#}", entry.day, kind.name(), e);
+                    failed.push(entry.day);
+                    continue;
+                }
+            };
+            for timing in &timings {
+                if !args.json {
+                    println!(
+                        "{:>3}  {:<6}  {:<5}  {:>10.2?}  {:>10.2?}  {:>10.2?}  {:>10.2?}",
+                        entry.day,
+                        kind.name(),
+                        timing.stage,
+                        timing.stats.min,
+                        timing.stats.median,
+                        timing.stats.mean,
+                        timing.stats.stddev
+                    );
+                }
+                records.push(BenchRecord::new(entry.day, kind, timing));
+            }
+        }
+    }
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&records)?);
+    }
+    if args.save || args.baseline.is_some() {
+        let mut history = History::load()?;
+        history.runs.push(BenchRun::new(records, args.baseline)?);
+        history.save()?;
+        eprintln!("Saved to {}", History::path().display());
+    }
+    if !failed.is_empty() {
+        failed.dedup();
+        bail!("Failed days: {}", join_days(&failed));
+    }
+    Ok(())
+}
+
+fn compare(args: CompareArgs) -> Result<()> {
+    let history = History::load()?;
+    let current = history
+        .latest()
+        .ok_or_else(|| eyre!("No saved runs yet; use `aoc bench --save` first"))?;
+    let reference =
+        history
+            .reference(args.against.as_deref())
+            .ok_or_else(|| match &args.against {
+                Some(name) => eyre!("No earlier run is named or at commit {:?}", name),
+                None => eyre!("Only one saved run, nothing to compare it with"),
+            })?;
+    println!(
+        "Comparing {} against {}\n",
+        current.describe(),
+        reference.describe()
+    );
+    println!(
+        "{:>3}  {:<6}  {:<5}  {:>10}  {:>10}  {:>8}",
+        "Day", "Input", "Stage", "Before", "After", "Change"
+    );
+    let mut regressed = Vec::new();
+    for comparison in history::compare(reference, current) {
+        let flag = if comparison.regressed(args.threshold) {
+            regressed.push(comparison.day);
+            "  REGRESSED"
+        } else {
+            ""
+        };
+        println!(
+            "{:>3}  {:<6}  {:<5}  {:>10.2?}  {:>10.2?}  {:>+7.1}%{}",
+            comparison.day,
+            comparison.input,
+            comparison.stage,
+            Duration::from_nanos(comparison.before_ns),
+            Duration::from_nanos(comparison.after_ns),
+            comparison.change_percent(),
+            flag
+        );
+    }
+    if !regressed.is_empty() {
+        regressed.dedup();
+        bail!(
+            "Days slower by more than {}%: {}",
+            args.threshold,
+            join_days(&regressed)
+        );
+    }
+    Ok(())
+}
+
+#[cfg(feature = "alloc-profile")]
+fn alloc(args: AllocArgs) -> Result<()> {
+    let days = match args.day {
+        DaySelection::Day(day) => {
+            registry::entry(day).ok_or_else(|| eyre!("Day {} is not implemented yet", day))?;
+            vec![day]
+        }
+        DaySelection::All => (1..=LAST_DAY).collect(),
+    };
+    let mut failed = Vec::new();
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>12}  {:>12}",
+        "Day", "Stage", "Allocs", "Bytes", "Peak live"
+    );
+    for entry in days.into_iter().filter_map(registry::entry) {
+        let stats = InputSource::for_day(entry.day, input_kind(args.sample))
+            .read()
+            .map_err(Into::into)
+            .and_then(|input| entry.profile(&input, args.part.parts()));
+        match stats {
+            Ok(stats) => {
+                for StageAllocs { stage, stats } in stats {
+                    println!(
+                        "{:>3}  {:<5}  {:>10}  {:>12}  {:>12}",
+                        entry.day, stage, stats.allocations, stats.bytes, stats.peak_live_bytes
+                    );
+                }
+            }
+            Err(e) => {
+                println!("{:>3}  error: {:#}", entry.day, e);
+                failed.push(entry.day);
+            }
+        }
+    }
+    if !failed.is_empty() {
+        bail!("Failed days: {}", join_days(&failed));
+    }
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> Result<()> {
+    let days = match args.day {
+        DaySelection::Day(day) => vec![day],
+        DaySelection::All => (1..=LAST_DAY).collect(),
+    };
+    let mut fetcher = Fetcher::new(fetch::session_token()?, fetch::default_cache_dir());
+    let mut failed = Vec::new();
+    for day in days {
+        let dir = day_dir(day);
+        if !dir.is_dir() {
+            continue;
+        }
+        let target = dir.join("input.txt");
+        if target.exists() && !args.force {
+            println!("Day {:>2}: {} already exists", day, target.display());
+            continue;
+        }
+        match fetcher
+            .input(day)
+            .and_then(|input| Ok(std::fs::write(&target, input)?))
+        {
+            Ok(()) => println!("Day {:>2}: wrote {}", day, target.display()),
+            Err(e) => {
+                println!("Day {:>2}: error: {:#}", day, e);
+                failed.push(day);
+            }
+        }
+    }
+    if !failed.is_empty() {
+        bail!("Failed days: {}", join_days(&failed));
+    }
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<()> {
+    let part = Part::from(args.part);
+    // Answers worked out here are recorded in the manifest once the site accepts them.
+    let (answer, solved) = match args.answer {
+        Some(answer) => (answer, None),
+        None => {
+            let entry = registry::entry(args.day)
+                .ok_or_else(|| eyre!("Day {} is not implemented yet", args.day))?;
+            let input = InputSource::for_day(args.day, InputKind::Real).read()?;
+            let outcome = entry
+                .solve(&input, &[part])?
+                .pop()
+                .ok_or_else(|| eyre!("Day {} gave no answer", args.day))?;
+            let answer = outcome.answer?;
+            if matches!(answer, Answer::Art(_)) {
+                bail!(
+                    "Part {} draws its answer; submit the letters it spells:{}",
+                    part,
+                    format_answer(&answer)
+                );
+            }
+            (answer.to_string(), Some(input_hash(&input)))
+        }
+    };
+    let submitter = Submitter::new(fetch::session_token()?, submit::default_log_path());
+    let verdict = submitter.submit(args.day, part, &answer)?;
+    println!("Day {} part {}: {} is {}", args.day, part, answer, verdict);
+    match verdict {
+        Submission::Correct => {
+            if let Some(hash) = solved {
+                let mut manifest = Manifest::load()?;
+                if manifest.expected(args.day, &hash, part).is_none() {
+                    manifest.record(args.day, &hash, part, answer);
+                    manifest.save()?;
+                }
+            }
+            Ok(())
+        }
+        _ => bail!("The answer wasn't accepted"),
+    }
+}
+
+fn gen(args: GenArgs) -> Result<()> {
+    let entry = registry::entry(args.day)
+        .ok_or_else(|| eyre!("Day {} is not implemented yet", args.day))?;
+    let size = args.size.unwrap_or(entry.default_size);
+    eprintln!(
+        "Day {}: seed {}, {} {}",
+        args.day, args.seed, size, entry.size_unit
+    );
+    print!("{}", entry.generate(args.seed, size));
+    Ok(())
+}
+
+fn watch(args: WatchArgs) -> Result<()> {
+    registry::entry(args.day).ok_or_else(|| eyre!("Day {} is not implemented yet", args.day))?;
+    let input = args.input.map_or_else(
+        || InputSource::for_day(args.day, input_kind(args.sample)),
+        InputSource::File,
+    );
+    let watcher = DayWatcher::new(WatchConfig {
+        day: args.day,
+        input,
+        release: args.release,
+    })?;
+    let mut previous = Vec::new();
+    loop {
+        match watcher.run() {
+            Ok(current) => {
+                print_changes(&watch::compare(&previous, &current));
+                previous = current;
+            }
+            // The next good run is compared with the last good one.
+            Err(e) => eprintln!("{:#}", e),
+        }
+        let [src, input] = watcher.watched();
+        eprintln!(
+            "\nWatching {} and {}; Ctrl-C to stop",
+            src.display(),
+            input.display()
+        );
+        let changed = watcher.wait_for_change()?;
+        let names: Vec<String> = changed
+            .iter()
+            .map(|path| {
+                let name = path.file_name().unwrap_or(path.as_os_str());
+                name.to_string_lossy().into_owned()
+            })
+            .collect();
+        println!("\nChanged: {}", names.join(", "));
+    }
+}
+
+const WATCH_ANSWER_INDENT: usize = 4 + 2 + 10 + 2 + 8 + 2;
+
+fn print_changes(changes: &[PartChange<'_>]) {
+    println!("{:>4}  {:>10}  {:>8}  Answer", "Part", "Time", "Change");
+    for change in changes {
+        let percent = change
+            .change_percent()
+            .map_or_else(|| "-".to_owned(), |percent| format!("{:+.1}%", percent));
+        let mut answer = change.current.answer_text();
+        if let Some(previous) = change.previous.filter(|_| change.answer_changed()) {
+            answer = format!("{}  (was {})", answer, previous.answer_text());
+        }
+        println!(
+            "{:>4}  {:>10.2?}  {:>8}  {}",
+            change.part,
+            change.current.duration(),
+            percent,
+            answer.replace(
+                '\n',
+                &format!("\n{:indent$}", "", indent = WATCH_ANSWER_INDENT)
+            )
+        );
+    }
+}
+
+fn input_kind(sample: bool) -> InputKind {
+    if sample {
+        InputKind::Sample
+    } else {
+        InputKind::Real
+    }
+}
+
+fn visualize(entry: &Entry, source: &InputSource, parts: &[Part]) -> Result<()> {
+    if !entry.can_visualize() {
+        bail!(
+            "Day {} can't be visualized; try one of days {}",
+            entry.day,
+            join_days(&days_that(Entry::can_visualize))
+        );
+    }
+    if !std::io::stdout().is_terminal() {
+        bail!("--visualize needs a terminal");
+    }
+    let input = source.read()?;
+    entry.visualize(&input, parts, &mut |animation| {
+        let title = format!("Day {} part {}", entry.day, animation.part);
+        let playback = common_utils::play(&title, animation.fps, animation.frames)?;
+        Ok(playback == Playback::Finished)
+    })
+}
+
+fn render(entry: &Entry, source: &InputSource, parts: &[Part], path: &Path) -> Result<()> {
+    if !entry.can_render() {
+        bail!(
+            "Day {} can't be rendered; try one of days {}",
+            entry.day,
+            join_days(&days_that(Entry::can_render))
+        );
+    }
+    let format = ImageFormat::from_path(path).ok_or_else(|| {
+        eyre!(
+            "Can't tell what image format {} should be; use .png, .ppm or .gif",
+            path.display()
+        )
+    })?;
+    let outputs: Vec<(Part, PathBuf)> = match parts {
+        [part] => vec![(*part, path.to_owned())],
+        _ => parts
+            .iter()
+            .map(|&part| (part, path_for_part(path, part)))
+            .collect(),
+    };
+    let input = source.read()?;
+    entry.render(&input, format, &outputs)?;
+    for (_, path) in outputs {
+        eprintln!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+/// `out.png` becomes `out-part1.png`.
+fn path_for_part(path: &Path, part: Part) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let mut file_name = format!("{}-part{}", stem, part);
+    if let Some(extension) = path.extension() {
+        file_name = format!("{}.{}", file_name, extension.to_string_lossy());
+    }
+    path.with_file_name(file_name)
+}
+
+/// The implemented days with some optional extra, like a visualization.
+fn days_that(has: impl Fn(&Entry) -> bool) -> Vec<u8> {
+    (1..=LAST_DAY)
+        .filter(|&day| registry::entry(day).is_some_and(&has))
+        .collect()
+}
+
+/// Solves the given parts of one day, printing a row per part, or adding a record per part to
+/// `records` if there are any.
+///
+/// Only a missing input or a parse failure is an error; failing parts are printed in place.
+fn run_day(
+    entry: &Entry,
+    source: &InputSource,
+    parts: &[Part],
+    records: Option<&mut Vec<AnswerRecord>>,
+) -> Result<()> {
+    let solved = solve_day(entry, source, parts)?;
+    report_day(entry.day, solved, records)
+}
+
+/// A day's answers, along with the hash of the input they came from.
+struct SolvedDay {
+    input_hash: String,
+    outcomes: Vec<PartOutcome>,
+}
+
+fn solve_day(entry: &Entry, source: &InputSource, parts: &[Part]) -> Result<SolvedDay> {
+    let input = source.read()?;
+    Ok(SolvedDay {
+        input_hash: input_hash(&input),
+        outcomes: entry.solve(&input, parts)?,
+    })
+}
+
+/// Prints or records each part's answer, failing if any part failed.
+fn report_day(
+    day: u8,
+    solved: SolvedDay,
+    mut records: Option<&mut Vec<AnswerRecord>>,
+) -> Result<()> {
+    let mut any_failed = false;
+    for outcome in solved.outcomes {
+        any_failed |= outcome.answer.is_err();
+        if let Some(records) = records.as_deref_mut() {
+            records.push(AnswerRecord::new(
+                day,
+                outcome.part,
+                &outcome.answer,
+                outcome.elapsed,
+                &solved.input_hash,
+            ));
+            continue;
+        }
+        let answer = match &outcome.answer {
+            Ok(answer) => format_answer(answer),
+            Err(e) => format!("error: {:#}", e),
+        };
+        print_row(day, Some(outcome.part), Some(outcome.elapsed), &answer);
+    }
+    if any_failed {
+        bail!("Day {} had a failing part", day);
+    }
+    Ok(())
+}
+
+const ANSWER_INDENT: usize = 3 + 2 + 4 + 2 + 10 + 2;
+
+fn print_header() {
+    println!("{:>3}  {:>4}  {:>10}  Answer", "Day", "Part", "Time");
+}
+
+fn print_row(day: u8, part: Option<Part>, elapsed: Option<Duration>, answer: &str) {
+    let part = part.map_or_else(|| "-".to_owned(), |part| part.to_string());
+    let elapsed = elapsed.map_or_else(|| "-".to_owned(), |elapsed| format!("{:.2?}", elapsed));
+    println!("{:>3}  {:>4}  {:>10}  {}", day, part, elapsed, answer);
+}
+
+/// Multi-line answers start on their own line, aligned with the answer column.
+fn format_answer(answer: &Answer) -> String {
+    indent_continuation(&answer.to_string())
+}
+
+/// Aligns every line after the first with the answer column.
+fn indent_continuation(text: &str) -> String {
+    text.replace('\n', &format!("\n{:indent$}", "", indent = ANSWER_INDENT))
+}
+
+fn join_days(days: &[u8]) -> String {
+    days.iter()
+        .map(u8::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
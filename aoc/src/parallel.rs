@@ -0,0 +1,153 @@
+use std::any::Any;
+use std::collections::{BTreeMap, VecDeque};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How many tasks to run at once, and how long each one gets.
+#[derive(Debug, Clone, Copy)]
+pub struct PoolConfig {
+    pub jobs: usize,
+    /// Wall-clock limit per task, counted from when it starts.
+    pub timeout: Duration,
+}
+
+/// How one task ended.
+#[derive(Debug)]
+pub enum TaskOutcome<T> {
+    Finished(T),
+    /// The task panicked; holds the panic message.
+    Panicked(String),
+    /// The task was still running at its deadline. Its thread is abandoned, not stopped, so it
+    /// keeps using a core until it finishes or the process exits.
+    TimedOut,
+}
+
+/// Runs each `(day, task)` on its own thread, at most `config.jobs` at a time, and calls
+/// `on_done` in the order the tasks were given as soon as each one and everything before it has
+/// ended.
+///
+/// A panic or a hang only ends that task: its slot goes to the next one.
+pub fn run_isolated<T, F>(
+    tasks: Vec<(u8, F)>,
+    config: PoolConfig,
+    mut on_done: impl FnMut(u8, TaskOutcome<T>),
+) where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let mut order: VecDeque<u8> = tasks.iter().map(|&(day, _)| day).collect();
+    let mut pending = tasks.into_iter();
+    let mut deadlines = BTreeMap::new();
+    let mut ended = BTreeMap::new();
+    loop {
+        while deadlines.len() < config.jobs.max(1) {
+            let Some((day, task)) = pending.next() else {
+                break;
+            };
+            let sender = sender.clone();
+            let spawned = thread::Builder::new()
+                .name(format!("day{}", day))
+                .spawn(move || {
+                    let outcome = match panic::catch_unwind(AssertUnwindSafe(task)) {
+                        Ok(value) => TaskOutcome::Finished(value),
+                        Err(payload) => TaskOutcome::Panicked(panic_message(payload)),
+                    };
+                    // The pool stops listening once the task times out.
+                    let _ = sender.send((day, outcome));
+                });
+            match spawned {
+                Ok(_) => {
+                    deadlines.insert(day, Instant::now() + config.timeout);
+                }
+                Err(e) => {
+                    let message = format!("couldn't start a thread: {}", e);
+                    ended.insert(day, TaskOutcome::Panicked(message));
+                }
+            }
+        }
+        if deadlines.is_empty() && ended.is_empty() {
+            break;
+        }
+        if let Some(&next_deadline) = deadlines.values().min() {
+            let wait = next_deadline.saturating_duration_since(Instant::now());
+            match receiver.recv_timeout(wait) {
+                Ok((day, outcome)) => {
+                    // Late results from tasks that already timed out are dropped.
+                    if deadlines.remove(&day).is_some() {
+                        ended.insert(day, outcome);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    let now = Instant::now();
+                    deadlines.retain(|&day, deadline| {
+                        let expired = *deadline <= now;
+                        if expired {
+                            ended.insert(day, TaskOutcome::TimedOut);
+                        }
+                        !expired
+                    });
+                }
+                Err(RecvTimeoutError::Disconnected) => unreachable!("the pool holds a sender"),
+            }
+        }
+        while let Some(outcome) = order.front().and_then(|day| ended.remove(day)) {
+            let day = order.pop_front().expect("front was just checked");
+            on_done(day, outcome);
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| (*message).to_owned())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panicked with a non-string payload".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isolates_panics_and_hangs() {
+        let tasks: Vec<(u8, Box<dyn FnOnce() -> u8 + Send>)> = vec![
+            (1, Box::new(|| 1)),
+            (2, Box::new(|| panic!("day 2 broke"))),
+            (
+                3,
+                Box::new(|| {
+                    thread::sleep(Duration::from_secs(60));
+                    3
+                }),
+            ),
+            (4, Box::new(|| 4)),
+        ];
+        let config = PoolConfig {
+            jobs: 2,
+            // Long enough that the quick tasks finish on a busy machine too.
+            timeout: Duration::from_secs(2),
+        };
+        let mut ended = Vec::new();
+        run_isolated(tasks, config, |day, outcome| {
+            let summary = match outcome {
+                TaskOutcome::Finished(value) => format!("finished {}", value),
+                TaskOutcome::Panicked(message) => format!("panicked: {}", message),
+                TaskOutcome::TimedOut => "timed out".to_owned(),
+            };
+            ended.push((day, summary));
+        });
+        assert_eq!(
+            ended,
+            [
+                (1, "finished 1".to_owned()),
+                (2, "panicked: day 2 broke".to_owned()),
+                (3, "timed out".to_owned()),
+                (4, "finished 4".to_owned()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use common_utils::{
+    Answer, AocResult, Frame, Generator, ImageFormat, Input, Part, Render, Solution, Visualize,
+};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+#[cfg(feature = "alloc-profile")]
+use crate::alloc_profile::{self, StageAllocs};
+use crate::bench::{self, BenchConfig, StageTiming};
+
+/// Number of days in the advent calendar.
+pub const LAST_DAY: u8 = 25;
+
+/// The outcome of running one part of a day.
+#[derive(Debug)]
+pub struct PartOutcome {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+/// One part's frames, as handed out by [`Entry::visualize`].
+pub struct Animation<'a> {
+    pub part: Part,
+    /// See [`Visualize::FPS`].
+    pub fps: u32,
+    pub frames: &'a mut dyn Iterator<Item = AocResult<Frame>>,
+}
+
+/// Shows an [`Animation`], saying whether to go on to the next part.
+pub type Show<'a> = dyn FnMut(Animation<'_>) -> Result<bool> + 'a;
+
+type VisualizeFn = fn(&Input, &[Part], &mut Show<'_>) -> Result<()>;
+type RenderFn = fn(&Input, ImageFormat, &[(Part, PathBuf)]) -> Result<()>;
+
+/// A type-erased [`Solution`] and its [`Generator`], so every day can live in one table.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub day: u8,
+    /// What a generated input's size counts; see [`Generator::SIZE_UNIT`].
+    pub size_unit: &'static str,
+    /// See [`Generator::DEFAULT_SIZE`].
+    pub default_size: usize,
+    solve: fn(&Input, &[Part]) -> Result<Vec<PartOutcome>>,
+    bench: fn(&Input, &[Part], &BenchConfig) -> Result<Vec<StageTiming>>,
+    generate: fn(u64, usize) -> String,
+    visualize: Option<VisualizeFn>,
+    render: Option<RenderFn>,
+    #[cfg(feature = "alloc-profile")]
+    profile: fn(&Input, &[Part]) -> Result<Vec<StageAllocs>>,
+}
+
+impl Entry {
+    const fn new<S: Generator>() -> Self {
+        Self {
+            day: S::DAY,
+            size_unit: S::SIZE_UNIT,
+            default_size: S::DEFAULT_SIZE,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+            generate: generate::<S>,
+            visualize: None,
+            render: None,
+            #[cfg(feature = "alloc-profile")]
+            profile: alloc_profile::profile::<S>,
+        }
+    }
+
+    /// Adds [`Entry::visualize`]; `S` must be the day's solution.
+    const fn with_visualize<S: Visualize>(self) -> Self {
+        Self {
+            visualize: Some(visualize::<S>),
+            ..self
+        }
+    }
+
+    /// Adds [`Entry::render`]; `S` must be the day's solution.
+    const fn with_render<S: Render>(self) -> Self {
+        Self {
+            render: Some(render::<S>),
+            ..self
+        }
+    }
+
+    /// Parses the input once, then runs each of the requested parts against it.
+    ///
+    /// A parse failure fails the whole day; a failing part is reported in its outcome.
+    pub fn solve(&self, input: &Input, parts: &[Part]) -> Result<Vec<PartOutcome>> {
+        (self.solve)(input, parts)
+    }
+
+    /// Times parsing and each of the requested parts; see [`bench::bench`].
+    pub fn bench(
+        &self,
+        input: &Input,
+        parts: &[Part],
+        config: &BenchConfig,
+    ) -> Result<Vec<StageTiming>> {
+        (self.bench)(input, parts, config)
+    }
+
+    /// A random input of about `size` [`size_unit`](Entry::size_unit). The same seed and size
+    /// always give the same input.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(seed, size)
+    }
+
+    pub fn can_visualize(&self) -> bool {
+        self.visualize.is_some()
+    }
+
+    /// Parses the input once, then hands each of the requested parts' frames to `show` until
+    /// it says to stop.
+    pub fn visualize(&self, input: &Input, parts: &[Part], show: &mut Show<'_>) -> Result<()> {
+        let visualize = self
+            .visualize
+            .ok_or_else(|| eyre!("Day {} can't be visualized", self.day))?;
+        visualize(input, parts, show)
+    }
+
+    pub fn can_render(&self) -> bool {
+        self.render.is_some()
+    }
+
+    /// Parses the input once, then saves a picture of each part to its path; see
+    /// [`Render`]. A GIF shows how the part got there, the other formats just where it ended.
+    pub fn render(
+        &self,
+        input: &Input,
+        format: ImageFormat,
+        outputs: &[(Part, PathBuf)],
+    ) -> Result<()> {
+        let render = self
+            .render
+            .ok_or_else(|| eyre!("Day {} can't be rendered", self.day))?;
+        render(input, format, outputs)
+    }
+
+    /// Counts allocations while parsing and solving; see [`alloc_profile::profile`].
+    #[cfg(feature = "alloc-profile")]
+    pub fn profile(&self, input: &Input, parts: &[Part]) -> Result<Vec<StageAllocs>> {
+        (self.profile)(input, parts)
+    }
+}
+
+fn solve<S: Solution>(input: &Input, parts: &[Part]) -> Result<Vec<PartOutcome>> {
+    let parsed = S::parse_input(input)?;
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = S::solve(&parsed, part);
+            PartOutcome {
+                part,
+                answer: answer.map_err(Into::into),
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect())
+}
+
+fn visualize<S: Visualize>(input: &Input, parts: &[Part], show: &mut Show<'_>) -> Result<()> {
+    let parsed = S::parse_input(input)?;
+    for &part in parts {
+        let mut frames = S::frames(&parsed, part)?;
+        let animation = Animation {
+            part,
+            fps: S::FPS,
+            frames: &mut frames,
+        };
+        if !show(animation)? {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn render<S: Render>(
+    input: &Input,
+    format: ImageFormat,
+    outputs: &[(Part, PathBuf)],
+) -> Result<()> {
+    let parsed = S::parse_input(input)?;
+    for (part, path) in outputs {
+        let file =
+            File::create(path).wrap_err_with(|| format!("Couldn't create {}", path.display()))?;
+        let mut out = BufWriter::new(file);
+        match format {
+            ImageFormat::Gif => common_utils::write_animation(
+                S::pictures(&parsed, *part)?,
+                S::FRAME_DELAY,
+                &mut out,
+            )?,
+            _ => common_utils::write_picture(&S::picture(&parsed, *part)?, format, &mut out)?,
+        }
+        out.flush()
+            .wrap_err_with(|| format!("Couldn't write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn generate<S: Generator>(seed: u64, size: usize) -> String {
+    S::generate(&mut ChaCha8Rng::seed_from_u64(seed), size)
+}
+
+static ENTRIES: [Entry; 15] = [
+    Entry::new::<day1::Day1>(),
+    Entry::new::<day2::Day2>(),
+    Entry::new::<day3::Day3>(),
+    Entry::new::<day4::Day4>(),
+    Entry::new::<day5::Day5>().with_visualize::<day5::Day5>(),
+    Entry::new::<day6::Day6>(),
+    Entry::new::<day7::Day7>(),
+    Entry::new::<day8::Day8>().with_render::<day8::Day8>(),
+    Entry::new::<day9::Day9>().with_visualize::<day9::Day9>(),
+    Entry::new::<day10::Day10>().with_visualize::<day10::Day10>(),
+    Entry::new::<day11::Day11>(),
+    Entry::new::<day12::Day12>().with_render::<day12::Day12>(),
+    Entry::new::<day13::Day13>(),
+    Entry::new::<day14::Day14>()
+        .with_visualize::<day14::Day14>()
+        .with_render::<day14::Day14>(),
+    Entry::new::<day15::Day15>().with_render::<day15::Day15>(),
+];
+
+/// Looks up the solution for a day, or `None` if that day is still a stub.
+pub fn entry(day: u8) -> Option<&'static Entry> {
+    ENTRIES.iter().find(|entry| entry.day == day)
+}
+
+#[test]
+fn entries_are_unique_and_in_order() {
+    assert!(ENTRIES.windows(2).all(|pair| pair[0].day < pair[1].day));
+    assert!(ENTRIES
+        .iter()
+        .all(|entry| (1..=LAST_DAY).contains(&entry.day)));
+}
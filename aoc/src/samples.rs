@@ -0,0 +1,38 @@
+use color_eyre::eyre::{ensure, eyre, Result, WrapErr};
+use common_utils::{day_dir, InputKind, InputSource, Part};
+
+use crate::answers::parse_answers;
+use crate::registry;
+
+/// The expected answers for `dayN/sample-input.txt`, kept next to it in the
+/// [`parse_answers`] format.
+pub const ANSWERS_FILE: &str = "sample-answers.txt";
+
+/// Solves one part of a day's sample input and compares it with the recorded answer.
+pub fn check_sample(day: u8, part: Part) -> Result<()> {
+    let answers_path = day_dir(day).join(ANSWERS_FILE);
+    let answers = std::fs::read_to_string(&answers_path)
+        .wrap_err_with(|| format!("Couldn't read {}", answers_path.display()))?;
+    let expected = parse_answers(&answers)
+        .wrap_err_with(|| format!("Couldn't parse {}", answers_path.display()))?
+        .into_iter()
+        .find_map(|(answer_part, answer)| (answer_part == part).then_some(answer))
+        .ok_or_else(|| eyre!("{} has no answer for part {}", answers_path.display(), part))?;
+
+    let entry = registry::entry(day).ok_or_else(|| eyre!("Day {} is not implemented yet", day))?;
+    let input = InputSource::for_day(day, InputKind::Sample).read()?;
+    let outcome = entry
+        .solve(&input, &[part])?
+        .pop()
+        .ok_or_else(|| eyre!("Day {} returned no outcome for part {}", day, part))?;
+    let actual = outcome.answer?.to_string();
+    ensure!(
+        actual == expected,
+        "Day {} part {} sample: expected {:?}, got {:?}",
+        day,
+        part,
+        expected,
+        actual
+    );
+    Ok(())
+}
@@ -0,0 +1,432 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use color_eyre::eyre::{bail, eyre, Result, WrapErr};
+use common_utils::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::fetch::{self, DEFAULT_BASE_URL, YEAR};
+
+/// Where past guesses are kept, next to the fetched inputs.
+pub fn default_log_path() -> PathBuf {
+    fetch::default_cache_dir().join("guesses.json")
+}
+
+/// Which way a wrong numeric answer was off, when the site says.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The answer is wrong, and no more guesses are accepted for `cooldown`.
+    Wrong {
+        hint: Option<Hint>,
+        cooldown: Duration,
+    },
+    /// The previous guess was too recent; nothing was checked.
+    TooSoon(Duration),
+    /// The part is already solved, or part 1 isn't yet.
+    WrongLevel,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => f.write_str("correct"),
+            Verdict::Wrong { hint, cooldown } => {
+                match hint {
+                    Some(Hint::TooHigh) => f.write_str("wrong, too high")?,
+                    Some(Hint::TooLow) => f.write_str("wrong, too low")?,
+                    None => f.write_str("wrong")?,
+                }
+                write!(f, " (next guess in {}s)", cooldown.as_secs())
+            }
+            Verdict::TooSoon(wait) => write!(f, "too soon, wait {}s", wait.as_secs()),
+            Verdict::WrongLevel => f.write_str("not the current level; already solved?"),
+        }
+    }
+}
+
+/// Reads the verdict out of the page the site sends back after a submission.
+pub fn parse_verdict(page: &str) -> Result<Verdict> {
+    let text = article_text(page);
+    let verdict = if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        let cooldown = after_phrase(&text, "wait ")
+            .and_then(parse_wait)
+            .unwrap_or(Duration::from_secs(60));
+        Verdict::Wrong { hint, cooldown }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = after_phrase(&text, "You have ")
+            .and_then(parse_wait)
+            .ok_or_else(|| eyre!("No wait time in {:?}", text))?;
+        Verdict::TooSoon(wait)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        bail!("Unrecognized response: {:?}", text);
+    };
+    Ok(verdict)
+}
+
+/// The text of the page's `<article>`, or the whole page, with tags dropped and whitespace
+/// collapsed.
+fn article_text(page: &str) -> String {
+    // Inside the article, we start partway through its opening tag.
+    let (article, mut in_tag) = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or((page, false), |(article, _)| (article, true));
+    let mut text = String::new();
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if in_tag => {}
+            _ => text.push(c),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn after_phrase<'a>(text: &'a str, phrase: &str) -> Option<&'a str> {
+    text.split_once(phrase).map(|(_, rest)| rest)
+}
+
+/// Parses the start of `one minute`, `5 minutes`, `43s` or `1m 12s`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let mut words = text.split_whitespace();
+    let first = words.next()?;
+    let minutes = |count: &str| match count {
+        "one" => Some(1),
+        _ => count.parse().ok(),
+    };
+    if words.next().is_some_and(|unit| unit.starts_with("minute")) {
+        return minutes(first).map(|m: u64| Duration::from_secs(m * 60));
+    }
+    let mut secs = 0;
+    let mut found = false;
+    for word in text.split_whitespace() {
+        let value = if let Some(m) = word.strip_suffix('m') {
+            m.parse::<u64>().ok()? * 60
+        } else if let Some(s) = word.strip_suffix('s') {
+            s.parse::<u64>().ok()?
+        } else {
+            break;
+        };
+        secs += value;
+        found = true;
+    }
+    found.then(|| Duration::from_secs(secs))
+}
+
+/// One answer sent to the site, and what it said.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub correct: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+/// Every guess made so far, and when the site will accept the next one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuessLog {
+    pub guesses: Vec<Guess>,
+    /// Seconds since the Unix epoch.
+    #[serde(default)]
+    pub not_before: u64,
+}
+
+impl GuessLog {
+    /// Reads the log, treating a missing file as an empty one.
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text)
+                .wrap_err_with(|| format!("Couldn't parse {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).wrap_err_with(|| format!("Couldn't read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .wrap_err_with(|| format!("Couldn't create {}", dir.display()))?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .wrap_err_with(|| format!("Couldn't write {}", path.display()))
+    }
+
+    /// Refuses answers the site would reject anyway: repeats, ones ruled out by an earlier
+    /// too high or too low, answers to solved parts, and anything during a cooldown.
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<()> {
+        let part = part_number(part);
+        let numeric = answer.parse::<i128>().ok();
+        for guess in self
+            .guesses
+            .iter()
+            .filter(|guess| (guess.day, guess.part) == (day, part))
+        {
+            if guess.correct {
+                bail!("Already solved with {}", guess.answer);
+            }
+            if guess.answer == answer {
+                bail!("{} was already guessed and was wrong", answer);
+            }
+            let ruled_out = match (numeric, guess.answer.parse::<i128>(), guess.hint) {
+                (Some(x), Ok(high), Some(Hint::TooHigh)) => x >= high,
+                (Some(x), Ok(low), Some(Hint::TooLow)) => x <= low,
+                _ => false,
+            };
+            if ruled_out {
+                bail!(
+                    "{} is ruled out, since {} was {}",
+                    answer,
+                    guess.answer,
+                    match guess.hint {
+                        Some(Hint::TooHigh) => "too high",
+                        _ => "too low",
+                    }
+                );
+            }
+        }
+        if now < self.not_before {
+            bail!(
+                "The site won't take another answer for {}s",
+                self.not_before - now
+            );
+        }
+        Ok(())
+    }
+
+    /// Notes what the site said about `answer`.
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, verdict: Verdict, now: u64) {
+        let part = part_number(part);
+        match verdict {
+            Verdict::Correct => self.guesses.push(Guess {
+                day,
+                part,
+                answer: answer.to_owned(),
+                correct: true,
+                hint: None,
+            }),
+            Verdict::Wrong { hint, cooldown } => {
+                self.guesses.push(Guess {
+                    day,
+                    part,
+                    answer: answer.to_owned(),
+                    correct: false,
+                    hint,
+                });
+                self.not_before = now + cooldown.as_secs();
+            }
+            Verdict::TooSoon(wait) => self.not_before = now + wait.as_secs(),
+            Verdict::WrongLevel => {}
+        }
+    }
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Posts answers, checking the [`GuessLog`] first so the site only sees plausible guesses.
+pub struct Submitter {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    log_path: PathBuf,
+}
+
+impl Submitter {
+    pub fn new(session: String, log_path: PathBuf) -> Self {
+        Self {
+            agent: fetch::agent(),
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            session,
+            log_path,
+        }
+    }
+
+    /// Points the submitter at another server, e.g. a local stand-in for tests.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict> {
+        let mut log = GuessLog::load(&self.log_path)?;
+        log.check(day, part, answer, now())?;
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, day);
+        let level = part.to_string();
+        let page = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|e| match e {
+                ureq::Error::Status(status, _) => eyre!("{} answered {}", url, status),
+                e => eyre!(e).wrap_err(format!("Couldn't post to {}", url)),
+            })?
+            .into_string()
+            .wrap_err_with(|| format!("Couldn't read the response from {}", url))?;
+        let verdict = parse_verdict(&page)?;
+
+        log.record(day, part, answer, verdict, now());
+        log.save(&self.log_path)?;
+        Ok(verdict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        let verdict = |article| parse_verdict(&page(article)).unwrap();
+        assert_eq!(
+            verdict("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a>"),
+            Verdict::Wrong {
+                hint: Some(Hint::TooHigh),
+                cooldown: Duration::from_secs(60)
+            }
+        );
+        assert_eq!(
+            verdict("That's not the right answer.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again."),
+            Verdict::Wrong {
+                hint: None,
+                cooldown: Duration::from_secs(300)
+            }
+        );
+        assert_eq!(
+            verdict("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 12s left to wait."),
+            Verdict::TooSoon(Duration::from_secs(72))
+        );
+        assert_eq!(
+            verdict("You don't seem to be solving the right level.  Did you already complete it?"),
+            Verdict::WrongLevel
+        );
+        assert!(parse_verdict("<html>Log in</html>").is_err());
+    }
+
+    #[test]
+    fn rules_out_guesses() {
+        let mut log = GuessLog::default();
+        let wrong = |hint| Verdict::Wrong {
+            hint,
+            cooldown: Duration::from_secs(60),
+        };
+        log.record(1, Part::One, "100", wrong(Some(Hint::TooHigh)), 0);
+        log.record(1, Part::One, "10", wrong(Some(Hint::TooLow)), 0);
+        log.record(1, Part::One, "abc", wrong(None), 0);
+        assert!(log.check(1, Part::One, "50", 59).is_err());
+        assert!(log.check(1, Part::One, "50", 60).is_ok());
+        assert!(log.check(1, Part::One, "100", 60).is_err());
+        assert!(log.check(1, Part::One, "120", 60).is_err());
+        assert!(log.check(1, Part::One, "5", 60).is_err());
+        assert!(log.check(1, Part::One, "abc", 60).is_err());
+        assert!(log.check(1, Part::Two, "100", 60).is_ok());
+        log.record(1, Part::One, "50", Verdict::Correct, 60);
+        assert!(log.check(1, Part::One, "51", 60).is_err());
+    }
+
+    /// Replays canned pages: 42 is correct, larger answers too high, anything else too low.
+    fn stand_in_site() -> (String, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let article = match body.as_str() {
+                    "level=1&answer=42" => "That's the right answer!",
+                    "level=1&answer=99" => "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+                    _ => "That's not the right answer; your answer is too low.  Please wait one minute before trying again.",
+                };
+                let _ = request.respond(Response::from_string(page(article)));
+            }
+        });
+        (base_url, requests)
+    }
+
+    #[test]
+    fn submits_and_remembers() {
+        let (base_url, requests) = stand_in_site();
+        let log_path =
+            std::env::temp_dir().join(format!("aoc-submit-{}/guesses.json", std::process::id()));
+        let _ = std::fs::remove_file(&log_path);
+        let submitter =
+            Submitter::new("secret".to_owned(), log_path.clone()).with_base_url(base_url);
+
+        assert_eq!(
+            submitter.submit(3, Part::One, "99").unwrap(),
+            Verdict::Wrong {
+                hint: Some(Hint::TooHigh),
+                cooldown: Duration::from_secs(60)
+            }
+        );
+        // Repeats and cooldowns are refused without asking the site.
+        assert!(submitter.submit(3, Part::One, "99").is_err());
+        assert!(submitter.submit(3, Part::One, "42").is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        let mut log = GuessLog::load(&log_path).unwrap();
+        log.not_before = 0;
+        log.save(&log_path).unwrap();
+        assert_eq!(
+            submitter.submit(3, Part::One, "42").unwrap(),
+            Verdict::Correct
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        std::fs::remove_dir_all(log_path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,75 @@
+use std::fs::File;
+use std::io::IsTerminal;
+use std::path::Path;
+use std::sync::Mutex;
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+/// Filter used when tracing is asked for without saying what to keep.
+pub const DEFAULT_FILTER: &str = "debug";
+
+/// Which spans and events to keep: `--trace=FILTER` wins over `$RUST_LOG`. Tracing is off when
+/// neither is set, unless there's a log file to write to.
+pub fn filter_directives(
+    flag: Option<&str>,
+    rust_log: Option<String>,
+    to_file: bool,
+) -> Option<String> {
+    flag.map(str::to_owned)
+        .or(rust_log.filter(|directives| !directives.trim().is_empty()))
+        .or_else(|| to_file.then(|| DEFAULT_FILTER.to_owned()))
+}
+
+/// Installs a subscriber for the solvers' spans and events, writing human-readable lines to
+/// stderr, or JSON lines to `json_file` if given. Each span logs its duration when it closes.
+///
+/// Does nothing if [`filter_directives`] says tracing is off.
+pub fn init(flag: Option<&str>, json_file: Option<&Path>) -> Result<()> {
+    let Some(directives) =
+        filter_directives(flag, std::env::var("RUST_LOG").ok(), json_file.is_some())
+    else {
+        return Ok(());
+    };
+    let filter = EnvFilter::try_new(&directives)
+        .wrap_err_with(|| format!("Invalid trace filter {:?}", directives))?;
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE);
+    match json_file {
+        Some(path) => {
+            let file = File::create(path)
+                .wrap_err_with(|| format!("Couldn't create {}", path.display()))?;
+            builder.json().with_writer(Mutex::new(file)).try_init()
+        }
+        None => builder
+            .with_ansi(std::io::stderr().is_terminal())
+            .with_writer(std::io::stderr)
+            .try_init(),
+    }
+    .map_err(|e| eyre!("Couldn't install the trace subscriber: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flag_then_env_then_file_default() {
+        let env = || Some("day11=debug".to_owned());
+        assert_eq!(filter_directives(None, None, false), None);
+        assert_eq!(
+            filter_directives(Some("trace"), env(), false).as_deref(),
+            Some("trace")
+        );
+        assert_eq!(
+            filter_directives(None, env(), true).as_deref(),
+            Some("day11=debug")
+        );
+        assert_eq!(
+            filter_directives(None, Some(" ".to_owned()), true).as_deref(),
+            Some(DEFAULT_FILTER)
+        );
+    }
+}
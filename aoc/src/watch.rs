@@ -0,0 +1,261 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use color_eyre::eyre::{bail, eyre, Result, WrapErr};
+use common_utils::{day_dir, workspace_root, InputKind, InputSource};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// How long the files must stay untouched before re-running, so an editor's save or a
+/// `git checkout` that touches several files only triggers one run.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// One part of a run, as `aoc run --format json` prints it.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Value,
+    #[serde(default)]
+    pub error: Option<String>,
+    pub duration_ns: u64,
+}
+
+impl PartRun {
+    /// The answer as `aoc run` prints it, or the error.
+    pub fn answer_text(&self) -> String {
+        if let Some(error) = &self.error {
+            return format!("error: {}", error);
+        }
+        match &self.answer {
+            Value::String(text) => text.clone(),
+            Value::Array(rows) => rows
+                .iter()
+                .map(|row| format!("\n{}", row.as_str().unwrap_or_default()))
+                .collect(),
+            other => other.to_string(),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.duration_ns)
+    }
+}
+
+/// One part of the latest run, next to the same part of the run before.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartChange<'a> {
+    pub part: u8,
+    pub current: &'a PartRun,
+    /// `None` on the first run, or if the part wasn't run last time.
+    pub previous: Option<&'a PartRun>,
+}
+
+impl PartChange<'_> {
+    /// Whether there was an earlier answer and this one differs from it.
+    pub fn answer_changed(&self) -> bool {
+        self.previous
+            .is_some_and(|previous| previous.answer_text() != self.current.answer_text())
+    }
+
+    /// How much slower (positive) or faster (negative) the part got.
+    pub fn change_percent(&self) -> Option<f64> {
+        let previous = self.previous?.duration_ns as f64;
+        (previous > 0.0).then(|| (self.current.duration_ns as f64 / previous - 1.0) * 100.0)
+    }
+}
+
+/// Pairs each part of `current` with the same part of `previous`.
+pub fn compare<'a>(previous: &'a [PartRun], current: &'a [PartRun]) -> Vec<PartChange<'a>> {
+    current
+        .iter()
+        .map(|run| PartChange {
+            part: run.part,
+            current: run,
+            previous: previous.iter().find(|previous| previous.part == run.part),
+        })
+        .collect()
+}
+
+/// What `aoc watch` watches and how it runs the day.
+#[derive(Debug, Clone)]
+pub struct WatchConfig {
+    pub day: u8,
+    pub input: InputSource,
+    /// Build with optimizations, so the timings are worth comparing.
+    pub release: bool,
+}
+
+/// Watches a day's sources and its input, and re-runs the day through cargo so source changes
+/// get compiled in.
+pub struct DayWatcher {
+    config: WatchConfig,
+    src: PathBuf,
+    input: PathBuf,
+    events: Receiver<notify::Result<Event>>,
+    // Stops watching when dropped.
+    _watcher: RecommendedWatcher,
+}
+
+impl DayWatcher {
+    pub fn new(config: WatchConfig) -> Result<Self> {
+        let canonical = |path: &Path| {
+            path.canonicalize()
+                .wrap_err_with(|| format!("Couldn't find {}", path.display()))
+        };
+        let src = canonical(&day_dir(config.day).join("src"))?;
+        let input = config
+            .input
+            .path()
+            .ok_or_else(|| eyre!("Can't watch stdin"))?;
+        let input = canonical(&input)?;
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        watcher.watch(&src, RecursiveMode::Recursive)?;
+        // Editors often save by replacing the file, which would end a watch on the file itself.
+        if let Some(input_dir) = input.parent() {
+            watcher.watch(input_dir, RecursiveMode::NonRecursive)?;
+        }
+        Ok(Self {
+            config,
+            src,
+            input,
+            events,
+            _watcher: watcher,
+        })
+    }
+
+    /// The day's source directory and its input file.
+    pub fn watched(&self) -> [&Path; 2] {
+        [&self.src, &self.input]
+    }
+
+    /// Builds the runner and solves both parts. Compiler errors go straight to stderr.
+    pub fn run(&self) -> Result<Vec<PartRun>> {
+        let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let mut command = Command::new(cargo);
+        command
+            .current_dir(workspace_root())
+            .args(["run", "--quiet", "--package", "aoc"]);
+        if self.config.release {
+            command.arg("--release");
+        }
+        command.args([
+            "--",
+            "run",
+            &self.config.day.to_string(),
+            "--format",
+            "json",
+        ]);
+        // The sample has to stay a sample, for days that solve it with different parameters.
+        if self.config.input.kind() == InputKind::Sample {
+            command.arg("--sample");
+        } else {
+            command.arg("--input").arg(&self.input);
+        }
+        let output = command
+            .stderr(Stdio::inherit())
+            .output()
+            .wrap_err("Couldn't start cargo")?;
+        // A failing part still prints its record; no output at all means no run.
+        if output.stdout.is_empty() {
+            bail!(
+                "Day {} didn't build or run ({})",
+                self.config.day,
+                output.status
+            );
+        }
+        serde_json::from_slice(&output.stdout).wrap_err("Couldn't read the answers from the run")
+    }
+
+    /// Blocks until a watched file is created, changed or removed and things have settled,
+    /// then returns every path that changed meanwhile.
+    pub fn wait_for_change(&self) -> Result<Vec<PathBuf>> {
+        let mut changed = Vec::new();
+        while changed.is_empty() {
+            self.collect_changes(self.events.recv()?, &mut changed)?;
+        }
+        while let Ok(event) = self.events.recv_timeout(SETTLE) {
+            self.collect_changes(event, &mut changed)?;
+        }
+        changed.sort();
+        changed.dedup();
+        Ok(changed)
+    }
+
+    fn collect_changes(
+        &self,
+        event: notify::Result<Event>,
+        changed: &mut Vec<PathBuf>,
+    ) -> Result<()> {
+        let event = event?;
+        // Reading the files, as the build and the run do, isn't a change.
+        if !matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) {
+            return Ok(());
+        }
+        changed.extend(
+            event
+                .paths
+                .into_iter()
+                .filter(|path| path.starts_with(&self.src) || *path == self.input),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(part: u8, answer: Value, duration_ns: u64) -> PartRun {
+        PartRun {
+            part,
+            answer,
+            error: None,
+            duration_ns,
+        }
+    }
+
+    #[test]
+    fn compares_parts_with_the_previous_run() {
+        let previous = [run(1, 1845.into(), 2000)];
+        let current = [
+            run(1, 1845.into(), 1500),
+            run(2, Value::from(vec!["#.", ".#"]), 100),
+        ];
+        let changes = compare(&previous, &current);
+        assert_eq!(changes.len(), 2);
+        assert!(!changes[0].answer_changed());
+        assert_eq!(changes[0].change_percent(), Some(-25.0));
+        // Part 2 wasn't run before, so there's nothing to compare.
+        assert!(!changes[1].answer_changed());
+        assert_eq!(changes[1].change_percent(), None);
+        assert_eq!(current[1].answer_text(), "\n#.\n.#");
+
+        let broken = [PartRun {
+            error: Some("No solution: nothing uncovered".to_owned()),
+            ..run(1, Value::Null, 3000)
+        }];
+        let changes = compare(&current, &broken);
+        assert!(changes[0].answer_changed());
+        assert_eq!(changes[0].change_percent(), Some(100.0));
+        assert_eq!(
+            broken[0].answer_text(),
+            "error: No solution: nothing uncovered"
+        );
+    }
+
+    #[test]
+    fn reads_run_output() -> Result<()> {
+        let json = r#"[{"day": 8, "part": 1, "answer": 1845, "answer_type": "int",
+            "duration_ns": 12, "input_hash": "ab"}]"#;
+        let runs: Vec<PartRun> = serde_json::from_str(json)?;
+        assert_eq!(runs, [run(1, 1845.into(), 12)]);
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+//! Every day's generator makes inputs its solution can answer, and makes the same one twice.
+
+use aoc::registry::{self, LAST_DAY};
+use color_eyre::eyre::Result;
+use common_utils::{InputSource, Part};
+
+#[test]
+fn generated_inputs_solve() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("aoc-generated-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    for entry in (1..=LAST_DAY).filter_map(registry::entry) {
+        for (seed, size) in [(0, 1), (1, 10), (2, entry.default_size / 4)] {
+            let generated = entry.generate(seed, size);
+            assert_eq!(generated, entry.generate(seed, size), "day {}", entry.day);
+            let path = dir.join(format!("day{}-{}.txt", entry.day, seed));
+            std::fs::write(&path, generated)?;
+            let input = InputSource::File(path).read()?;
+            // Day 15's part 2 walks sensor edges millions of points long whatever the size, which
+            // takes seconds in a debug build; `aoc run --input` covers it.
+            let parts: &[Part] = if entry.day == 15 {
+                &[Part::One]
+            } else {
+                &Part::BOTH
+            };
+            for outcome in entry.solve(&input, parts)? {
+                if let Err(e) = outcome.answer {
+                    panic!(
+                        "Day {} part {} failed on seed {}, size {}: {:#}",
+                        entry.day, outcome.part, seed, size, e
+                    );
+                }
+            }
+        }
+    }
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
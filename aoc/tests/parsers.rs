@@ -0,0 +1,28 @@
+//! Each day's line parsers are part of its public API, so other crates can parse the same input
+//! without going through `Solution::parse`.
+
+use common_utils::{Direction4, Point2};
+
+#[test]
+fn line_parsers_are_public() {
+    let (_, step) = day9::parse::parse_move("R 4").unwrap();
+    assert_eq!((step.dir, step.steps), (Direction4::Right, 4));
+
+    let (_, instruction) = day10::parser::instruction("addx -5").unwrap();
+    assert!(matches!(instruction, day10::Instruction::Add(-5)));
+
+    let monkey = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3";
+    let (_, monkey) = day11::parser::monkey(monkey).unwrap();
+    assert_eq!(monkey.items, [79, 98]);
+    assert_eq!((monkey.test.argument, monkey.test.is_divisible), (23, 2));
+
+    let line = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+    let (_, sensor) = day15::parser::sensor(line).unwrap();
+    assert_eq!(sensor.nearest_beacon, Point2::new(-2, 15));
+    assert_eq!(sensor.l_1_radius, 7);
+}
@@ -0,0 +1,40 @@
+//! Every day that can be rendered saves its sample in each format.
+
+use std::path::PathBuf;
+
+use aoc::registry::{self, LAST_DAY};
+use color_eyre::eyre::Result;
+use common_utils::{ImageFormat, InputKind, InputSource, Part};
+
+#[test]
+fn sample_images() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let formats: [(ImageFormat, &[u8]); 3] = [
+        (ImageFormat::Png, b"\x89PNG"),
+        (ImageFormat::Ppm, b"P6\n"),
+        (ImageFormat::Gif, b"GIF89a"),
+    ];
+    let entries = (1..=LAST_DAY)
+        .filter_map(registry::entry)
+        .filter(|entry| entry.can_render());
+    for entry in entries {
+        let input = InputSource::for_day(entry.day, InputKind::Sample).read()?;
+        for (format, magic) in formats {
+            let outputs: Vec<(Part, PathBuf)> = Part::BOTH
+                .iter()
+                .map(|&part| {
+                    let name = format!("day{}-part{}-{:?}", entry.day, part, format);
+                    (part, dir.join(name))
+                })
+                .collect();
+            entry.render(&input, format, &outputs)?;
+            for (_, path) in outputs {
+                let image = std::fs::read(&path)?;
+                assert!(image.starts_with(magic), "{}", path.display());
+            }
+        }
+    }
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
@@ -0,0 +1,7 @@
+//! One test per day and part, checking `dayN/sample-input.txt` against `dayN/sample-answers.txt`.
+
+use aoc::samples::check_sample;
+use color_eyre::eyre::Result;
+use common_utils::Part;
+
+include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
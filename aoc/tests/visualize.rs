@@ -0,0 +1,42 @@
+//! Every day that can be watched makes a frame for each step of its sample, without errors.
+
+use aoc::registry::{self, LAST_DAY};
+use color_eyre::eyre::Result;
+use common_utils::{Answer, InputKind, InputSource, Part};
+
+#[test]
+fn sample_frames() -> Result<()> {
+    let entries = (1..=LAST_DAY)
+        .filter_map(registry::entry)
+        .filter(|entry| entry.can_visualize());
+    for entry in entries {
+        let input = InputSource::for_day(entry.day, InputKind::Sample).read()?;
+        let mut frame_counts = Vec::new();
+        entry.visualize(&input, &Part::BOTH, &mut |animation| {
+            let mut count = 0;
+            for frame in animation.frames {
+                let frame = frame?;
+                assert!(!frame.rows.is_empty(), "day {}: {:?}", entry.day, frame);
+                if let Some((row, col)) = frame.focus {
+                    assert!(frame.rows[row].chars().nth(col).is_some(), "{:?}", frame);
+                }
+                count += 1;
+            }
+            assert!(count > 1, "day {} part {}", entry.day, animation.part);
+            frame_counts.push(count);
+            Ok(true)
+        })?;
+        assert_eq!(frame_counts.len(), 2, "day {}", entry.day);
+        if entry.day == 14 {
+            // A frame for the empty cave, then one for each grain.
+            let grains: Vec<Answer> = entry
+                .solve(&input, &Part::BOTH)?
+                .into_iter()
+                .map(|outcome| outcome.answer)
+                .collect::<Result<_>>()?;
+            let counts = frame_counts.iter().map(|&count| (count - 1).into());
+            assert!(grains.into_iter().eq(counts));
+        }
+    }
+    Ok(())
+}
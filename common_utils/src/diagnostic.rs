@@ -0,0 +1,192 @@
+use std::fmt;
+
+use nom::{
+    combinator::all_consuming,
+    error::{ErrorKind, VerboseError, VerboseErrorKind},
+    Finish, IResult, Parser,
+};
+
+/// The result type for nom parsers whose failures should become [`ParseDiagnostic`]s.
+pub type ParseResult<'s, O> = IResult<&'s str, O, VerboseError<&'s str>>;
+
+/// Where and why parsing failed, with the offending line for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    /// The input being parsed, if known; filled in by [`crate::Solution::parse_input`].
+    pub file: Option<String>,
+    /// 1-based line of the failure.
+    pub line: usize,
+    /// 1-based column (in chars) of the failure.
+    pub column: usize,
+    /// What the parser was looking for, e.g. a `context` label or a token.
+    pub expected: String,
+    /// The failing line with a caret under the failure point.
+    pub snippet: String,
+}
+
+impl ParseDiagnostic {
+    /// A failure at 1-based `column` of `source_line`, which is line `line` of the input; for
+    /// parsers that work line by line rather than through nom.
+    pub fn new(line: usize, column: usize, source_line: &str, expected: impl Into<String>) -> Self {
+        let source_line = source_line.trim_end_matches('\r');
+        let before: String = source_line.chars().take(column.saturating_sub(1)).collect();
+        Self {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            snippet: render_snippet(line, source_line, &before),
+        }
+    }
+
+    /// Builds a diagnostic from a failure parsing `input`, which starts at line `first_line` of
+    /// the file.
+    pub fn from_verbose(input: &str, first_line: usize, err: VerboseError<&str>) -> Self {
+        let remaining = err.errors.first().map_or("", |(remaining, _)| *remaining);
+        let offset = input.len().saturating_sub(remaining.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let source_line = input[line_start..line_end].trim_end_matches('\r');
+        let line = first_line + before.matches('\n').count();
+        let column = input[line_start..offset].chars().count() + 1;
+
+        Self {
+            file: None,
+            line,
+            column,
+            expected: describe_expected(&err),
+            snippet: render_snippet(line, source_line, &input[line_start..offset]),
+        }
+    }
+}
+
+/// A missing character is named directly; otherwise the innermost `context` label wins, since
+/// it names the construct that actually failed.
+fn describe_expected(err: &VerboseError<&str>) -> String {
+    if let Some((_, VerboseErrorKind::Char(c))) = err.errors.first() {
+        return format!("'{}'", c);
+    }
+    let context = err.errors.iter().find_map(|(_, kind)| match kind {
+        VerboseErrorKind::Context(label) => Some(*label),
+        _ => None,
+    });
+    if let Some(label) = context {
+        return label.to_owned();
+    }
+    match err.errors.first().map(|(_, kind)| kind) {
+        Some(VerboseErrorKind::Nom(ErrorKind::Eof)) => "end of input".to_owned(),
+        Some(VerboseErrorKind::Nom(kind)) => kind.description().to_lowercase(),
+        _ => "valid input".to_owned(),
+    }
+}
+
+fn render_snippet(line: usize, source_line: &str, before_failure: &str) -> String {
+    let gutter = line.to_string().len();
+    // Keep tabs so the caret lines up however the terminal renders them.
+    let padding: String = before_failure
+        .chars()
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    format!(
+        "{:gutter$} |\n{} | {}\n{:gutter$} | {}^",
+        "",
+        line,
+        source_line,
+        "",
+        padding,
+        gutter = gutter
+    )
+}
+
+impl fmt::Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {} at ", self.expected)?;
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        write!(f, "{}:{}\n{}", self.line, self.column, self.snippet)
+    }
+}
+
+impl std::error::Error for ParseDiagnostic {}
+
+/// Runs `parser` over all of `input`, which starts at line `first_line` of the file.
+pub fn parse_from_line<'s, O, ParseFn>(
+    input: &'s str,
+    first_line: usize,
+    parser: ParseFn,
+) -> Result<O, ParseDiagnostic>
+where
+    ParseFn: Parser<&'s str, O, VerboseError<&'s str>>,
+{
+    match all_consuming(parser)(input).finish() {
+        Ok((_, out)) => Ok(out),
+        Err(e) => Err(ParseDiagnostic::from_verbose(input, first_line, e)),
+    }
+}
+
+/// Runs `parser` over all of `line`, reporting failures as being on line 1.
+pub fn parse_line<'s, O, ParseFn>(line: &'s str, parser: ParseFn) -> Result<O, ParseDiagnostic>
+where
+    ParseFn: Parser<&'s str, O, VerboseError<&'s str>>,
+{
+    parse_from_line(line, 1, parser)
+}
+
+/// Runs `parser` over each line of `input`, tagging failures with their line number.
+pub fn parse_lines<'s, O, ParseFn>(
+    input: &'s str,
+    mut parser: ParseFn,
+) -> impl Iterator<Item = Result<O, ParseDiagnostic>> + 's
+where
+    ParseFn: Parser<&'s str, O, VerboseError<&'s str>> + 's,
+    O: 's,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(move |(i, line)| parse_from_line(line, i + 1, |s| parser.parse(s)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use nom::{bytes::complete::tag, character::complete::u32 as nom_u32, error::context};
+
+    fn pair(s: &str) -> ParseResult<'_, (u32, u32)> {
+        let (s, a) = context("first number", nom_u32)(s)?;
+        let (s, _) = tag(",")(s)?;
+        let (s, b) = context("second number", nom_u32)(s)?;
+        Ok((s, (a, b)))
+    }
+
+    #[test]
+    fn points_at_failing_line_and_column() {
+        let err = parse_lines("1,2\n3,4\n5,x\n", pair)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.expected, "second number");
+        assert_eq!(err.snippet, "  |\n3 | 5,x\n  |   ^");
+    }
+
+    #[test]
+    fn hand_built_diagnostics() {
+        let err = ParseDiagnostic::new(12, 3, "A Q\r", "X, Y or Z");
+        assert_eq!(
+            err.to_string(),
+            "expected X, Y or Z at 12:3\n   |\n12 | A Q\n   |   ^"
+        );
+    }
+
+    #[test]
+    fn multi_line_input_and_leftovers() {
+        let err = parse_from_line("1,2\n3,4 ", 10, pair).unwrap_err();
+        assert_eq!((err.line, err.column), (10, 4));
+        assert_eq!(err.expected, "end of input");
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+
+use crate::ParseDiagnostic;
+
+/// The result type of everything that reads, parses or solves a puzzle.
+pub type AocResult<T> = Result<T, AocError>;
+
+/// Everything that can go wrong between reading a puzzle input and answering it.
+#[derive(Debug)]
+pub enum AocError {
+    /// The input couldn't be read.
+    Io {
+        /// Where the input was being read from.
+        source_name: String,
+        error: std::io::Error,
+    },
+    /// The input doesn't match the puzzle's format.
+    Parse(ParseDiagnostic),
+    /// The input is well-formed, but describes something the puzzle rules out, like moving a
+    /// crate off an empty stack.
+    InvalidState(String),
+    /// The search for an answer came up empty, or found more than one.
+    NoSolution(String),
+}
+
+impl AocError {
+    pub fn invalid_state(message: impl Into<String>) -> Self {
+        AocError::InvalidState(message.into())
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        AocError::NoSolution(message.into())
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { source_name, .. } => {
+                write!(f, "Couldn't read input from {}", source_name)
+            }
+            AocError::Parse(diagnostic) => diagnostic.fmt(f),
+            AocError::InvalidState(message) => write!(f, "Invalid puzzle state: {}", message),
+            AocError::NoSolution(message) => write!(f, "No solution: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { error, .. } => Some(error),
+            // The diagnostic is already part of the message.
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseDiagnostic> for AocError {
+    fn from(diagnostic: ParseDiagnostic) -> Self {
+        AocError::Parse(diagnostic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn messages() {
+        let diagnostic = ParseDiagnostic::new(2, 3, "A Q", "X, Y or Z");
+        assert_eq!(
+            AocError::from(diagnostic.clone()).to_string(),
+            diagnostic.to_string()
+        );
+        assert_eq!(
+            AocError::invalid_state("Can't cd .. out of /").to_string(),
+            "Invalid puzzle state: Can't cd .. out of /"
+        );
+        let io = AocError::Io {
+            source_name: "day1/input.txt".to_owned(),
+            error: std::io::ErrorKind::NotFound.into(),
+        };
+        assert_eq!(io.to_string(), "Couldn't read input from day1/input.txt");
+        assert!(io.source().is_some());
+    }
+}
@@ -0,0 +1,18 @@
+use rand::Rng;
+
+use crate::Solution;
+
+/// Makes random puzzle inputs, to try a [`Solution`] on more than the one real input.
+pub trait Generator: Solution {
+    /// What `size` counts in [`generate`](Generator::generate), e.g. `"elves"`.
+    const SIZE_UNIT: &'static str;
+
+    /// A size that gives an input about as big as a real one.
+    const DEFAULT_SIZE: usize;
+
+    /// A syntactically valid input that both parts can answer, with about `size` of
+    /// [`SIZE_UNIT`](Generator::SIZE_UNIT) in it.
+    ///
+    /// Sizes too small (or too big) for the puzzle to make sense are clamped.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String;
+}
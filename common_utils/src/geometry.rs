@@ -0,0 +1,427 @@
+//! Points and directions on the integer lattice.
+//!
+//! Everything here uses screen axes: `x` grows to the right and `y` grows *down*, so `y` is the
+//! row of a [`Grid`](crate::Grid) and `x` its column.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::iter;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::Position;
+
+/// Integer types usable as coordinates.
+pub trait Coord:
+    Copy + Ord + Hash + Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// `|self - other|`, without overflowing for unsigned types.
+    fn distance(self, other: Self) -> Self {
+        self.max(other) - self.min(other)
+    }
+
+    /// `|self - other|` as a `u128`, which holds it for every coordinate type, even where
+    /// [`distance`](Coord::distance) would overflow, like `i32::MIN` to `i32::MAX`.
+    fn wide_distance(self, other: Self) -> u128;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn wide_distance(self, other: Self) -> u128 {
+                    self.abs_diff(other) as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coord> Point2<T> {
+    pub const ORIGIN: Self = Self::new(T::ZERO, T::ZERO);
+
+    /// The L1 (taxicab) distance.
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// The L∞ (king's move) distance.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    /// Every point at exactly Manhattan distance `radius`, each once, going clockwise from the
+    /// rightmost one.
+    pub fn manhattan_circle(self, radius: T) -> impl Iterator<Item = Self>
+    where
+        T: Neg<Output = T>,
+    {
+        let offsets = iter::successors(Some(T::ZERO), move |&offset| {
+            (offset + T::ONE < radius).then(|| offset + T::ONE)
+        })
+        .filter(move |_| radius > T::ZERO);
+        let center = iter::once(self).filter(move |_| radius == T::ZERO);
+        center.chain(offsets.flat_map(move |offset| {
+            let rest = radius - offset;
+            [
+                Self::new(self.x + rest, self.y + offset),
+                Self::new(self.x - offset, self.y + rest),
+                Self::new(self.x - rest, self.y - offset),
+                Self::new(self.x + offset, self.y - rest),
+            ]
+        }))
+    }
+
+    /// Moves `n` steps in `dir`.
+    pub fn step(self, dir: impl Into<Point2<T>>, n: T) -> Self {
+        self + dir.into() * n
+    }
+}
+
+impl<T: Coord + Neg<Output = T>> Point2<T> {
+    /// Rotates a quarter turn clockwise (as seen on screen) about the origin.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates a quarter turn anticlockwise (as seen on screen) about the origin.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+/// Grid positions are `(row, column)`, i.e. `(y, x)`.
+impl From<Point2<usize>> for Position {
+    fn from(point: Point2<usize>) -> Self {
+        (point.y, point.x)
+    }
+}
+
+impl From<Position> for Point2<usize> {
+    fn from((row, col): Position) -> Self {
+        Self::new(col, row)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Coord> Point3<T> {
+    pub const ORIGIN: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x
+            .distance(other.x)
+            .max(self.y.distance(other.y))
+            .max(self.z.distance(other.z))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+/// The four orthogonal directions, clockwise from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The unit step in this direction.
+    pub fn offset<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Direction4::Up => (0, -1),
+            Direction4::Right => (1, 0),
+            Direction4::Down => (0, 1),
+            Direction4::Left => (-1, 0),
+        };
+        Point2::new(x.into(), y.into())
+    }
+}
+
+impl<T: From<i8>> From<Direction4> for Point2<T> {
+    fn from(dir: Direction4) -> Self {
+        dir.offset()
+    }
+}
+
+/// The eight compass directions, clockwise from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turns clockwise by `eighths` eighths of a full turn.
+    pub fn turn_right(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Turns anticlockwise by `eighths` eighths of a full turn.
+    pub fn turn_left(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + 8 - eighths % 8) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right(4)
+    }
+
+    /// The unit step in this direction; diagonals move one along each axis.
+    pub fn offset<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        };
+        Point2::new(x.into(), y.into())
+    }
+}
+
+impl<T: From<i8>> From<Direction8> for Point2<T> {
+    fn from(dir: Direction8) -> Self {
+        dir.offset()
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(dir: Direction4) -> Self {
+        Direction8::ALL[dir as usize * 2]
+    }
+}
+
+/// The smallest axis-aligned rectangle holding a set of points, inclusive at both corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+impl<T: Coord> BoundingBox<T> {
+    /// `None` if there are no points.
+    pub fn from_points(points: impl IntoIterator<Item = Point2<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounds = Self {
+            min: first,
+            max: first,
+        };
+        points.for_each(|point| bounds.include(point));
+        Some(bounds)
+    }
+
+    /// Grows the box to cover `point`.
+    pub fn include(&mut self, point: Point2<T>) {
+        self.min = Point2::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point2::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point2<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manhattan_circle_matches_distance() {
+        let center = Point2::new(3i32, -2);
+        for radius in 0..5 {
+            let circle: Vec<_> = center.manhattan_circle(radius).collect();
+            assert_eq!(circle.len(), (4 * radius).max(1) as usize);
+            assert!(circle.iter().all(|&p| center.manhattan(p) == radius));
+            let mut deduped = circle.clone();
+            deduped.sort();
+            deduped.dedup();
+            assert_eq!(deduped.len(), circle.len());
+        }
+    }
+
+    #[test]
+    fn rotation_follows_screen_axes() {
+        let up: Point2<i32> = Direction4::Up.offset();
+        assert_eq!(up.rotate_right(), Direction4::Right.offset());
+        assert_eq!(up.rotate_left(), Direction4::Left.offset());
+        assert_eq!(Direction4::Left.turn_right(), Direction4::Up);
+        assert_eq!(Direction8::Up.turn_left(1), Direction8::UpLeft);
+        assert_eq!(Point2::new(0u8, 5).chebyshev(Point2::new(3, 1)), 4);
+    }
+}
@@ -0,0 +1,335 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{AocError, AocResult, ParseDiagnostic};
+
+/// A `(row, column)` position in a [`Grid`].
+pub type Position = (usize, usize);
+
+const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A dense, row-major 2D grid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![fill; rows * cols],
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.cols)
+            .flat_map(|col| self.column(col).cloned())
+            .collect();
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            cells,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from equal-length rows.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> AocResult<Self> {
+        let mut cells = Vec::new();
+        let mut num_rows = 0;
+        let mut cols = None;
+        for row in rows {
+            match cols {
+                None => cols = Some(row.len()),
+                Some(cols) if cols != row.len() => {
+                    return Err(AocError::invalid_state(format!(
+                        "Row {} has {} cells, expected {}",
+                        num_rows + 1,
+                        row.len(),
+                        cols
+                    )))
+                }
+                Some(_) => (),
+            }
+            cells.extend(row);
+            num_rows += 1;
+        }
+        Ok(Self {
+            rows: num_rows,
+            cols: cols.unwrap_or(0),
+            cells,
+        })
+    }
+
+    /// Parses a character map, one row per line, mapping each cell with `cell`.
+    ///
+    /// `cell` rejects a character by saying what it expected instead. Fails on an empty map,
+    /// ragged rows, or the first rejected cell.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(Position, char) -> Result<T, &'static str>,
+    ) -> AocResult<Self> {
+        let mut cells = Vec::new();
+        let mut cols = None;
+        let mut rows = 0;
+        for (row, line) in input.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            let row_cols = line.chars().count();
+            match cols {
+                None => cols = Some(row_cols),
+                Some(cols) if cols != row_cols => {
+                    let expected = format!("a row of {} cells", cols);
+                    let column = row_cols.min(cols) + 1;
+                    return Err(ParseDiagnostic::new(row + 1, column, line, expected).into());
+                }
+                Some(_) => (),
+            }
+            for (col, c) in line.chars().enumerate() {
+                let value = cell((row, col), c)
+                    .map_err(|expected| ParseDiagnostic::new(row + 1, col + 1, line, expected))?;
+                cells.push(value);
+            }
+            rows += 1;
+        }
+        if cells.is_empty() {
+            return Err(ParseDiagnostic::new(1, 1, "", "a non-empty map").into());
+        }
+        Ok(Self {
+            rows,
+            cols: cols.unwrap_or(0),
+            cells,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, (row, col): Position) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.cells.get(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (row, col): Position) -> Option<&mut T> {
+        if row < self.rows && col < self.cols {
+            self.cells.get_mut(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Top to bottom; `.rev()` for bottom to top.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.cols, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    pub fn column_mut(
+        &mut self,
+        col: usize,
+    ) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator {
+        assert!(col < self.cols, "column {} out of bounds", col);
+        self.cells.iter_mut().skip(col).step_by(self.cols)
+    }
+
+    /// Down and to the right from `(row, col)`; `.rev()` walks back up to it.
+    pub fn diagonal(
+        &self,
+        (row, col): Position,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        let len = self
+            .rows
+            .saturating_sub(row)
+            .min(self.cols.saturating_sub(col));
+        (0..len).map(move |i| &self[(row + i, col + i)])
+    }
+
+    /// Down and to the left from `(row, col)`; `.rev()` walks back up to it.
+    pub fn anti_diagonal(
+        &self,
+        (row, col): Position,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        let len = if col < self.cols {
+            self.rows.saturating_sub(row).min(col + 1)
+        } else {
+            0
+        };
+        (0..len).map(move |i| &self[(row + i, col - i)])
+    }
+
+    /// All cells in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// All cells in row-major order, with their positions.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Position, &T)> {
+        let cols = self.cols;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / cols, i % cols), cell))
+    }
+
+    /// The first position whose cell matches `pred`, in row-major order.
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.cells
+            .iter()
+            .position(pred)
+            .map(|i| (i / self.cols, i % self.cols))
+    }
+
+    /// The orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> {
+        self.offsets(pos, &OFFSETS4)
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> {
+        self.offsets(pos, &OFFSETS8)
+    }
+
+    fn offsets(
+        &self,
+        (row, col): Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> {
+        let (rows, cols) = (self.rows, self.cols);
+        offsets.iter().filter_map(move |&(d_row, d_col)| {
+            let row = row.checked_add_signed(d_row)?;
+            let col = col.checked_add_signed(d_col)?;
+            (row < rows && col < cols).then_some((row, col))
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Renders one character per cell, one line per row.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut out = String::with_capacity(self.rows * (self.cols + 1));
+        for row in 0..self.rows {
+            out.extend(self.row(row).iter().map(&mut f));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Position) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "({}, {}) out of bounds",
+            row,
+            col
+        );
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Position) -> &mut T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "({}, {}) out of bounds",
+            row,
+            col
+        );
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+/// Cells are written back to back, one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            for cell in self.row(row) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456", |_, c| c.to_digit(10).ok_or("a digit")).unwrap()
+    }
+
+    #[test]
+    fn lines_and_transpose() {
+        let grid = digits();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).rev().copied().collect::<Vec<_>>(), [6, 3]);
+        assert_eq!(grid.diagonal((0, 1)).copied().collect::<Vec<_>>(), [2, 6]);
+        assert_eq!(
+            grid.anti_diagonal((0, 2)).copied().collect::<Vec<_>>(),
+            [3, 5]
+        );
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = digits();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn rejects_bad_maps() {
+        assert!(Grid::parse("12\n3", |_, c| Ok(c)).is_err());
+        let err = Grid::parse("12\n3x", |_, c| c.to_digit(10).ok_or("a digit")).unwrap_err();
+        assert!(format!("{}", err).contains("2:2"));
+    }
+}
@@ -0,0 +1,182 @@
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::{AocError, AocResult};
+
+/// Which of a day's checked-in inputs to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    /// `dayN/input.txt`
+    Real,
+    /// `dayN/sample-input.txt`
+    Sample,
+}
+
+impl InputKind {
+    /// `real` or `sample`, for display.
+    pub fn name(self) -> &'static str {
+        match self {
+            InputKind::Real => "real",
+            InputKind::Sample => "sample",
+        }
+    }
+
+    fn file_name(self) -> &'static str {
+        match self {
+            InputKind::Real => "input.txt",
+            InputKind::Sample => "sample-input.txt",
+        }
+    }
+}
+
+/// Where to read puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    /// One of the day's checked-in inputs, so its [`InputKind`] is known.
+    Day {
+        day: u8,
+        kind: InputKind,
+    },
+}
+
+impl InputSource {
+    /// Interprets a command-line argument: `-` is stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.into())
+        }
+    }
+
+    /// A day's checked-in input, read from `dayN/input.txt` or `dayN/sample-input.txt` under the
+    /// workspace root.
+    pub fn for_day(day: u8, kind: InputKind) -> Self {
+        InputSource::Day { day, kind }
+    }
+
+    /// Whether this is a day's sample input. Stdin and any other file count as real input.
+    pub fn kind(&self) -> InputKind {
+        match self {
+            InputSource::Day { kind, .. } => *kind,
+            InputSource::Stdin | InputSource::File(_) => InputKind::Real,
+        }
+    }
+
+    /// The file to read, or `None` for stdin.
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            InputSource::Stdin => None,
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Day { day, kind } => Some(day_dir(*day).join(kind.file_name())),
+        }
+    }
+
+    /// Reads the whole input into memory.
+    pub fn read(&self) -> AocResult<Input> {
+        let contents = match self.path() {
+            None => {
+                let mut contents = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut contents)
+                    .map(|_| contents)
+            }
+            Some(path) => std::fs::read_to_string(path),
+        }
+        .map_err(|error| AocError::Io {
+            source_name: self.to_string(),
+            error,
+        })?;
+        Ok(Input {
+            source: self.clone(),
+            contents,
+        })
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.path() {
+            None => f.write_str("<stdin>"),
+            Some(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// The directory holding the workspace `Cargo.toml` and the `dayN` crates.
+pub fn workspace_root() -> &'static Path {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir)
+}
+
+/// A day's crate directory, `dayN` under the workspace root, where its inputs live.
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_root().join(format!("day{}", day))
+}
+
+/// A puzzle input, read once and held in memory.
+#[derive(Debug, Clone)]
+pub struct Input {
+    source: InputSource,
+    contents: String,
+}
+
+impl Input {
+    pub fn source(&self) -> &InputSource {
+        &self.source
+    }
+
+    pub fn kind(&self) -> InputKind {
+        self.source.kind()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.contents
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.contents.lines()
+    }
+}
+
+/// Resolves a day binary's input from its command line.
+///
+/// The first argument is a path, `-` for stdin, or `--sample` for the day's sample input; with
+/// no arguments the day's real input is used.
+pub fn input_from_args(day: u8) -> AocResult<Input> {
+    let source = match std::env::args().nth(1).as_deref() {
+        None => InputSource::for_day(day, InputKind::Real),
+        Some("--sample") => InputSource::for_day(day, InputKind::Sample),
+        Some(arg) => InputSource::from_arg(arg),
+    };
+    source.read()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conventional_paths() {
+        let sample = InputSource::for_day(7, InputKind::Sample);
+        assert!(sample.path().unwrap().ends_with("day7/sample-input.txt"));
+        assert_eq!(sample.kind(), InputKind::Sample);
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        // Only the checked-in sample is known to be one, whatever a file is called.
+        assert_eq!(
+            InputSource::from_arg("day7/sample-input.txt").kind(),
+            InputKind::Real
+        );
+    }
+
+    #[test]
+    fn missing_file_reports_path() {
+        let err = InputSource::from_arg("no/such/input.txt")
+            .read()
+            .unwrap_err();
+        assert!(format!("{}", err).contains("no/such/input.txt"));
+    }
+}
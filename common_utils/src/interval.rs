@@ -0,0 +1,291 @@
+use std::cmp::Ordering;
+
+use crate::Coord;
+
+/// An inclusive range of integers, `start..=end`, that always holds at least one value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Coord> Interval<T> {
+    /// `None` if `start > end`.
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    pub fn point(value: T) -> Self {
+        Self {
+            start: value,
+            end: value,
+        }
+    }
+
+    pub fn start(self) -> T {
+        self.start
+    }
+
+    pub fn end(self) -> T {
+        self.end
+    }
+
+    /// How many values the interval holds. Only `MIN..=MAX` of a 128-bit type holds more than
+    /// `u128::MAX`, and saturates.
+    // An interval is never empty, so there's no `is_empty` to go with this.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(self) -> u128 {
+        self.end.wide_distance(self.start).saturating_add(1)
+    }
+
+    pub fn contains(self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether every value of `other` is also in `self`.
+    pub fn covers(self, other: Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(self, other: Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Whether `self` and `other` overlap or sit side by side, so their union is one interval.
+    fn touches(self, other: Self) -> bool {
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        second.start <= first.end || second.start.wide_distance(first.end) == 1
+    }
+}
+
+/// A set of integers stored as sorted, disjoint, non-adjacent [`Interval`]s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Coord> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The set's intervals, in ascending order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many values the set holds, saturating like [`Interval::len`].
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().fold(0, |total: u128, interval| {
+            total.saturating_add(interval.len())
+        })
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals
+            .binary_search_by(|interval| {
+                if interval.end < value {
+                    Ordering::Less
+                } else if interval.start > value {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        // Everything from `first` to `last` (exclusive) merges with the new interval.
+        let first = self.intervals.partition_point(|existing| {
+            existing.end < interval.start && !existing.touches(interval)
+        });
+        let last = first
+            + self.intervals[first..]
+                .iter()
+                .take_while(|existing| existing.touches(interval))
+                .count();
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, existing| Interval {
+                start: merged.start.min(existing.start),
+                end: merged.end.max(existing.end),
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: Interval<T>) {
+        *self = self.difference(&Self::from(interval));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut all = self.intervals.clone();
+        all.extend_from_slice(&other.intervals);
+        all.into_iter().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&a), Some(&b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intervals.extend(a.intersection(b));
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    /// The values in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut removed = other.intervals.iter().peekable();
+        for &interval in &self.intervals {
+            let mut rest = Some(interval);
+            while let (Some(current), Some(&&cut)) = (rest, removed.peek()) {
+                if cut.end < current.start {
+                    removed.next();
+                    continue;
+                }
+                if cut.start > current.end {
+                    break;
+                }
+                if cut.start > current.start {
+                    intervals.push(Interval {
+                        start: current.start,
+                        end: cut.start - T::ONE,
+                    });
+                }
+                if cut.end < current.end {
+                    rest = Some(Interval {
+                        start: cut.end + T::ONE,
+                        end: current.end,
+                    });
+                    removed.next();
+                } else {
+                    rest = None;
+                }
+            }
+            intervals.extend(rest);
+        }
+        Self { intervals }
+    }
+
+    /// The values missing between the lowest and highest values in the set.
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals.windows(2).map(|pair| Interval {
+            start: pair[0].end + T::ONE,
+            end: pair[1].start - T::ONE,
+        })
+    }
+}
+
+impl<T: Coord> From<Interval<T>> for RangeSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self {
+            intervals: vec![interval],
+        }
+    }
+}
+
+/// Sorts then merges, so building a set from `n` intervals is `O(n log n)`.
+impl<T: Coord> FromIterator<Interval<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut sorted: Vec<_> = iter.into_iter().collect();
+        sorted.sort_unstable();
+        let mut intervals: Vec<Interval<T>> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if last.touches(interval) => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+        Self { intervals }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> RangeSet<i32> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end).unwrap())
+            .collect()
+    }
+
+    fn bounds(set: &RangeSet<i32>) -> Vec<(i32, i32)> {
+        set.intervals()
+            .iter()
+            .map(|interval| (interval.start(), interval.end()))
+            .collect()
+    }
+
+    #[test]
+    fn normalizes_on_insert() {
+        let mut ranges = set(&[(10, 12), (0, 2), (4, 5)]);
+        assert_eq!(bounds(&ranges), [(0, 2), (4, 5), (10, 12)]);
+        ranges.insert(Interval::point(3));
+        assert_eq!(bounds(&ranges), [(0, 5), (10, 12)]);
+        ranges.insert(Interval::new(7, 20).unwrap());
+        assert_eq!(bounds(&ranges), [(0, 5), (7, 20)]);
+        assert_eq!(ranges.len(), 20);
+        assert!(ranges.contains(7) && !ranges.contains(6));
+        assert_eq!(ranges.gaps().map(|gap| gap.len()).collect::<Vec<_>>(), [1]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(bounds(&a.union(&b)), [(0, 30)]);
+        assert_eq!(bounds(&a.intersection(&b)), [(5, 10), (20, 25)]);
+        assert_eq!(bounds(&a.difference(&b)), [(0, 4), (26, 30)]);
+        let mut c = a.clone();
+        c.remove(Interval::new(2, 3).unwrap());
+        assert_eq!(bounds(&c), [(0, 1), (4, 10), (20, 30)]);
+    }
+
+    #[test]
+    fn extremes_dont_overflow() {
+        let everything = Interval::new(i32::MIN, i32::MAX).unwrap();
+        assert_eq!(everything.len(), 1 << 32);
+        let halves = [
+            Interval::new(i32::MIN, -1).unwrap(),
+            Interval::new(0, i32::MAX).unwrap(),
+        ];
+        let merged: RangeSet<i32> = halves.into_iter().collect();
+        assert_eq!(merged.intervals(), [everything]);
+        let apart: RangeSet<i32> = [Interval::point(i32::MIN), Interval::point(i32::MAX)]
+            .into_iter()
+            .collect();
+        assert_eq!(apart.len(), 2);
+        assert_eq!(
+            RangeSet::from(Interval::new(0, u128::MAX).unwrap()).len(),
+            u128::MAX
+        );
+    }
+}
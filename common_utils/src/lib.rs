@@ -1,24 +1,29 @@
-use color_eyre::eyre::{eyre, Result};
-use nom::{combinator::all_consuming, error::ParseError, Finish, Parser};
+mod diagnostic;
+mod error;
+#[cfg(feature = "generator")]
+mod generator;
+mod geometry;
+mod grid;
+mod input;
+mod interval;
+#[cfg(feature = "render")]
+mod render;
+mod sections;
+mod solution;
+#[cfg(feature = "visualize")]
+mod visualize;
 
-pub fn get_buffered_input() -> std::io::BufReader<std::fs::File> {
-    let mut args = std::env::args();
-    args.next().unwrap();
-    let input_path = args.next().unwrap();
-    let input_file = std::fs::OpenOptions::new()
-        .read(true)
-        .open(input_path)
-        .unwrap();
-    std::io::BufReader::new(input_file)
-}
-
-pub fn parse_line<'s, O, E, ParseFn>(line: &'s str, parser: ParseFn) -> Result<O>
-where
-    E: ParseError<&'s str> + std::error::Error,
-    ParseFn: Parser<&'s str, O, E>,
-{
-    match all_consuming(parser)(line).finish() {
-        Ok((_, out)) => Ok(out),
-        Err(e) => Err(eyre!("Parsing error: {}", e)),
-    }
-}
+pub use diagnostic::{parse_from_line, parse_line, parse_lines, ParseDiagnostic, ParseResult};
+pub use error::{AocError, AocResult};
+#[cfg(feature = "generator")]
+pub use generator::Generator;
+pub use geometry::{BoundingBox, Coord, Direction4, Direction8, Point2, Point3};
+pub use grid::{Grid, Position};
+pub use input::{day_dir, input_from_args, workspace_root, Input, InputKind, InputSource};
+pub use interval::{Interval, RangeSet};
+#[cfg(feature = "render")]
+pub use render::{write_animation, write_picture, ImageFormat, Picture, Render, Rgb};
+pub use sections::{sections, Section, Sections};
+pub use solution::{run, Answer, Part, Solution};
+#[cfg(feature = "visualize")]
+pub use visualize::{play, Frame, Playback, Visualize};
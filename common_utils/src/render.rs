@@ -0,0 +1,249 @@
+use std::borrow::Cow;
+use std::io::Write;
+use std::iter;
+use std::path::Path;
+use std::time::Duration;
+
+use color_eyre::eyre::{ensure, eyre, Result};
+
+use crate::{AocResult, Grid, Part, Position, Solution};
+
+/// A colour as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// A picture whose pixels are indices into a palette of up to 256 colours.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    pub pixels: Grid<u8>,
+    pub palette: Vec<Rgb>,
+}
+
+impl Picture {
+    /// One pixel per cell of `grid`, coloured by the palette index `colour` picks for it.
+    pub fn from_grid<T>(
+        grid: &Grid<T>,
+        palette: Vec<Rgb>,
+        mut colour: impl FnMut(Position, &T) -> u8,
+    ) -> Self {
+        let mut pixels = Grid::new(grid.rows(), grid.cols(), 0);
+        for (position, cell) in grid.enumerate() {
+            pixels[position] = colour(position, cell);
+        }
+        Self { pixels, palette }
+    }
+
+    fn check(&self) -> Result<()> {
+        ensure!(
+            (1..=256).contains(&self.palette.len()),
+            "A palette needs 1 to 256 colours, not {}",
+            self.palette.len()
+        );
+        if let Some(&index) = self
+            .pixels
+            .iter()
+            .find(|&&index| index as usize >= self.palette.len())
+        {
+            return Err(eyre!(
+                "Palette index {} is past the {} colours",
+                index,
+                self.palette.len()
+            ));
+        }
+        ensure!(
+            self.pixels.rows() > 0 && self.pixels.cols() > 0,
+            "Can't write an empty picture"
+        );
+        Ok(())
+    }
+
+    /// How many image pixels each grid cell becomes, so small grids aren't specks.
+    fn scale(&self) -> usize {
+        const MIN_SIDE: usize = 512;
+        (MIN_SIDE / self.pixels.rows().max(self.pixels.cols())).max(1)
+    }
+
+    /// Palette indices, row-major, with every cell blown up to a `scale` by `scale` square.
+    fn scaled_indices(&self, scale: usize) -> Vec<u8> {
+        (0..self.pixels.rows())
+            .flat_map(|row| iter::repeat_n(self.pixels.row(row), scale))
+            .flat_map(|cells| {
+                cells
+                    .iter()
+                    .flat_map(move |&index| iter::repeat_n(index, scale))
+            })
+            .collect()
+    }
+
+    fn flat_palette(&self) -> Vec<u8> {
+        self.palette.concat()
+    }
+}
+
+/// A [`Solution`] whose state can be saved as an image.
+pub trait Render: Solution {
+    /// How long each picture of an animation is shown.
+    const FRAME_DELAY: Duration = Duration::from_millis(100);
+
+    /// What solving `part` ends with.
+    fn picture(parsed: &Self::Parsed, part: Part) -> AocResult<Picture>;
+
+    /// How solving `part` gets there, for an animation. Just the final
+    /// [`picture`](Render::picture) unless a day has more to show; every picture must be the
+    /// same size.
+    fn pictures(
+        parsed: &Self::Parsed,
+        part: Part,
+    ) -> AocResult<impl Iterator<Item = AocResult<Picture>> + '_> {
+        Ok(iter::once(Self::picture(parsed, part)))
+    }
+}
+
+/// Image formats [`write_picture`] and [`write_animation`] can make.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    /// Binary PPM (`P6`), for tools that don't read PNG.
+    Ppm,
+    /// Animated, looping forever.
+    Gif,
+}
+
+impl ImageFormat {
+    /// Picks the format from a file's extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "png" => Some(ImageFormat::Png),
+            "ppm" => Some(ImageFormat::Ppm),
+            "gif" => Some(ImageFormat::Gif),
+            _ => None,
+        }
+    }
+}
+
+/// Writes one picture, scaled up if it's small. A GIF gets a single frame.
+pub fn write_picture(picture: &Picture, format: ImageFormat, mut out: impl Write) -> Result<()> {
+    picture.check()?;
+    let scale = picture.scale();
+    let width = picture.pixels.cols() * scale;
+    let height = picture.pixels.rows() * scale;
+    let indices = picture.scaled_indices(scale);
+    match format {
+        ImageFormat::Png => {
+            let mut encoder = png::Encoder::new(out, width.try_into()?, height.try_into()?);
+            encoder.set_color(png::ColorType::Indexed);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder.set_palette(picture.flat_palette());
+            let mut writer = encoder.write_header()?;
+            writer.write_image_data(&indices)?;
+            writer.finish()?;
+        }
+        ImageFormat::Ppm => {
+            write!(out, "P6\n{} {}\n255\n", width, height)?;
+            let rgb: Vec<u8> = indices
+                .iter()
+                .flat_map(|&index| picture.palette[index as usize])
+                .collect();
+            out.write_all(&rgb)?;
+        }
+        ImageFormat::Gif => write_animation(iter::once(Ok(picture.clone())), Duration::ZERO, out)?,
+    }
+    Ok(())
+}
+
+/// Writes `pictures` as a looping GIF, showing each for `delay`. The pictures must all be the
+/// size of the first, which also sets the scale.
+pub fn write_animation(
+    pictures: impl IntoIterator<Item = AocResult<Picture>>,
+    delay: Duration,
+    out: impl Write,
+) -> Result<()> {
+    let mut pictures = pictures.into_iter();
+    let first = pictures
+        .next()
+        .ok_or_else(|| eyre!("An animation needs at least one picture"))??;
+    first.check()?;
+    let (rows, cols) = (first.pixels.rows(), first.pixels.cols());
+    let scale = first.scale();
+    let global_palette = first.flat_palette();
+    let mut encoder = gif::Encoder::new(
+        out,
+        (cols * scale).try_into()?,
+        (rows * scale).try_into()?,
+        &global_palette,
+    )?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    // GIF delays are in hundredths of a second.
+    let delay = (delay.as_millis() / 10).try_into().unwrap_or(u16::MAX);
+    for picture in iter::once(Ok(first)).chain(pictures) {
+        let picture = picture?;
+        picture.check()?;
+        ensure!(
+            (picture.pixels.rows(), picture.pixels.cols()) == (rows, cols),
+            "Every picture in an animation must be {}x{}",
+            cols,
+            rows
+        );
+        let palette = picture.flat_palette();
+        let frame = gif::Frame {
+            width: (cols * scale) as u16,
+            height: (rows * scale) as u16,
+            delay,
+            buffer: Cow::Owned(picture.scaled_indices(scale)),
+            palette: (palette != global_palette).then_some(palette),
+            ..gif::Frame::default()
+        };
+        encoder.write_frame(&frame)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Picture {
+        let grid = Grid::new(2, 2, ());
+        let palette = vec![[0, 0, 0], [255, 255, 255]];
+        Picture::from_grid(&grid, palette, |(row, col), _| ((row + col) % 2) as u8)
+    }
+
+    #[test]
+    fn ppm_scales_up_through_the_palette() -> Result<()> {
+        let mut ppm = Vec::new();
+        write_picture(&checkerboard(), ImageFormat::Ppm, &mut ppm)?;
+        let header = b"P6\n512 512\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        let pixels = &ppm[header.len()..];
+        assert_eq!(pixels.len(), 512 * 512 * 3);
+        // Row 0: 256 black pixels then 256 white; row 256 the other way round.
+        assert_eq!(&pixels[255 * 3..257 * 3], [0, 0, 0, 255, 255, 255]);
+        let row_256 = &pixels[256 * 512 * 3..];
+        assert_eq!(&row_256[..3], [255, 255, 255]);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_bad_pictures() {
+        let mut picture = checkerboard();
+        picture.palette.pop();
+        assert!(write_picture(&picture, ImageFormat::Png, Vec::new()).is_err());
+        let small = Picture::from_grid(&Grid::new(1, 1, ()), vec![[0, 0, 0]], |_, _| 0);
+        let mismatched = [Ok(checkerboard()), Ok(small)];
+        assert!(write_animation(mismatched, Duration::ZERO, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn formats_from_extensions() {
+        assert_eq!(
+            ImageFormat::from_path(Path::new("out.PNG")),
+            Some(ImageFormat::Png)
+        );
+        assert_eq!(
+            ImageFormat::from_path(Path::new("a/b.gif")),
+            Some(ImageFormat::Gif)
+        );
+        assert_eq!(ImageFormat::from_path(Path::new("out.jpg")), None);
+        assert_eq!(ImageFormat::from_path(Path::new("out")), None);
+    }
+}
@@ -0,0 +1,106 @@
+use nom::{error::VerboseError, Parser};
+
+use crate::{parse_from_line, ParseDiagnostic};
+
+/// A run of non-blank lines from the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// 1-based line number of the section's first line.
+    pub first_line: usize,
+    /// The section's lines, without the trailing line ending.
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.text.lines()
+    }
+
+    /// Runs `parser` over the whole section, reporting failures at their line in the input.
+    pub fn parse<O, ParseFn>(&self, parser: ParseFn) -> Result<O, ParseDiagnostic>
+    where
+        ParseFn: Parser<&'a str, O, VerboseError<&'a str>>,
+    {
+        parse_from_line(self.text, self.first_line, parser)
+    }
+}
+
+/// Iterator over the blank-line-separated sections of an input; see [`sections`].
+#[derive(Debug, Clone)]
+pub struct Sections<'a> {
+    rest: &'a str,
+    next_line: usize,
+}
+
+/// Splits `input` into sections separated by one or more blank lines.
+///
+/// Both `\n` and `\r\n` line endings work, and the last section doesn't need a trailing blank
+/// line.
+pub fn sections(input: &str) -> Sections<'_> {
+    Sections {
+        rest: input,
+        next_line: 1,
+    }
+}
+
+fn split_first_line(s: &str) -> (&str, &str) {
+    match s.find('\n') {
+        Some(i) => (s[..i].trim_end_matches('\r'), &s[i + 1..]),
+        None => (s.trim_end_matches('\r'), ""),
+    }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = Section<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.rest.is_empty() {
+                return None;
+            }
+            let (line, after) = split_first_line(self.rest);
+            if !line.is_empty() {
+                break;
+            }
+            self.rest = after;
+            self.next_line += 1;
+        }
+
+        let start = self.rest;
+        let first_line = self.next_line;
+        let mut end = 0;
+        while !self.rest.is_empty() {
+            let (line, after) = split_first_line(self.rest);
+            if line.is_empty() {
+                break;
+            }
+            end = (line.as_ptr() as usize - start.as_ptr() as usize) + line.len();
+            self.rest = after;
+            self.next_line += 1;
+        }
+        Some(Section {
+            first_line,
+            text: &start[..end],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_on_blank_lines() {
+        let found: Vec<_> = sections("a\nb\n\n\nc\r\nd\r\n\r\ne")
+            .map(|section| (section.first_line, section.text))
+            .collect();
+        assert_eq!(found, [(1, "a\nb"), (5, "c\r\nd"), (8, "e")]);
+    }
+
+    #[test]
+    fn ignores_leading_and_trailing_separators() {
+        let found: Vec<_> = sections("\n1\n2\n\n").map(|section| section.text).collect();
+        assert_eq!(found, ["1\n2"]);
+        assert_eq!(sections("").count(), 0);
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+
+use color_eyre::eyre::Result;
+
+use crate::{input_from_args, AocError, AocResult, Input, InputKind};
+
+/// A single day's puzzle, split into parsing and the two parts.
+///
+/// Parsing happens once; both parts get a shared reference to the parsed input, so anything
+/// that needs to mutate state (e.g. a simulation) works on its own copy.
+pub trait Solution {
+    /// Day of the advent calendar this solves, 1-based.
+    const DAY: u8;
+
+    type Parsed;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed>;
+
+    fn part1(parsed: &Self::Parsed) -> AocResult<Answer>;
+
+    fn part2(parsed: &Self::Parsed) -> AocResult<Answer>;
+
+    /// Parses input known to be the example or the real puzzle. Days whose puzzle text sets
+    /// different parameters for the example (like day 15's row) override this; the rest just
+    /// [`parse`](Solution::parse).
+    fn parse_as(input: &str, _kind: InputKind) -> AocResult<Self::Parsed> {
+        Self::parse(input)
+    }
+
+    /// Parses a whole [`Input`] inside a `parse` span, naming it in any [`AocError::Parse`]
+    /// that comes back.
+    fn parse_input(input: &Input) -> AocResult<Self::Parsed> {
+        let _span =
+            tracing::info_span!("parse", day = Self::DAY, source = %input.source()).entered();
+        Self::parse_as(input.as_str(), input.kind()).map_err(|mut err| {
+            if let AocError::Parse(diagnostic) = &mut err {
+                diagnostic.file = Some(input.source().to_string());
+            }
+            err
+        })
+    }
+
+    /// Solves `part` inside a `part1` or `part2` span.
+    fn solve(parsed: &Self::Parsed, part: Part) -> AocResult<Answer> {
+        match part {
+            Part::One => {
+                let _span = tracing::info_span!("part1", day = Self::DAY).entered();
+                Self::part1(parsed)
+            }
+            Part::Two => {
+                let _span = tracing::info_span!("part2", day = Self::DAY).entered();
+                Self::part2(parsed)
+            }
+        }
+    }
+}
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+/// A puzzle answer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// Wide enough for any of the primitive integers up to 64 bits, signed or not.
+    Int(i128),
+    Text(String),
+    /// Rows of pixels that spell out the answer when printed, like day 10's CRT.
+    Art(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{}", x),
+            Answer::Text(s) => f.write_str(s),
+            Answer::Art(rows) => {
+                for row in rows {
+                    write!(f, "\n{}", row)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! impl_answer_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                // None of these are wider than 64 bits, so every value fits.
+                fn from(val: $int) -> Self {
+                    Answer::Int(val as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<i128> for Answer {
+    fn from(val: i128) -> Self {
+        Answer::Int(val)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(val: String) -> Self {
+        Answer::Text(val)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(val: &str) -> Self {
+        Answer::Text(val.to_owned())
+    }
+}
+
+/// Reads the input selected on the command line, then solves and prints both parts.
+pub fn run<S: Solution>() -> Result<()> {
+    let input = input_from_args(S::DAY)?;
+    let parsed = S::parse_input(&input)?;
+    for part in Part::BOTH {
+        println!("Day {} part {}: {}", S::DAY, part, S::solve(&parsed, part)?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn big_integers_stay_exact() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(usize::MAX), Answer::Int(usize::MAX as i128));
+        assert_eq!(Answer::from(i64::MIN).to_string(), "-9223372036854775808");
+    }
+}
@@ -0,0 +1,255 @@
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use color_eyre::eyre::Result;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+
+use crate::{AocResult, Part, Position, Solution};
+
+/// One picture of a simulation in progress.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Shown above the picture, e.g. which step this is.
+    pub caption: String,
+    pub rows: Vec<String>,
+    /// The `(row, column)` to keep on screen when the picture is bigger than the terminal.
+    pub focus: Option<Position>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, rows: Vec<String>) -> Self {
+        Self {
+            caption: caption.into(),
+            rows,
+            focus: None,
+        }
+    }
+
+    pub fn with_focus(self, focus: Position) -> Self {
+        Self {
+            focus: Some(focus),
+            ..self
+        }
+    }
+}
+
+/// A [`Solution`] whose simulation can be watched step by step.
+pub trait Visualize: Solution {
+    /// How many frames a second to [`play`] at to begin with.
+    const FPS: u32 = 30;
+
+    /// The states solving `part` goes through, in order. Frames are made as they're asked
+    /// for, so a long simulation costs nothing until it's watched; an error ends it.
+    fn frames(
+        parsed: &Self::Parsed,
+        part: Part,
+    ) -> AocResult<impl Iterator<Item = AocResult<Frame>> + '_>;
+}
+
+/// How [`play`] ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Playback {
+    /// Every frame was shown.
+    Finished,
+    /// The viewer quit early.
+    Quit,
+}
+
+/// The terminal never redraws faster than this; higher speeds skip frames instead.
+const MAX_REDRAWS_PER_SECOND: u32 = 60;
+const MAX_FPS: u32 = 3840;
+/// Lines above the picture: the caption, the status line and a gap.
+const HEADER_LINES: u16 = 3;
+
+/// Shows `frames` full-screen, starting at `fps` frames a second.
+///
+/// Space plays or pauses, the right arrow (or `.`) steps one frame, `+` and `-` double or halve
+/// the speed, and `q` or Esc quits. After the last frame, any other key returns.
+pub fn play(
+    title: &str,
+    fps: u32,
+    frames: impl Iterator<Item = AocResult<Frame>>,
+) -> Result<Playback> {
+    let _screen = Screen::enter()?;
+    let mut player = Player {
+        frames,
+        frame: None,
+        shown: 0,
+        finished: false,
+        paused: false,
+        fps: fps.clamp(1, MAX_FPS),
+    };
+    player.advance(1)?;
+    let mut next_tick = Instant::now();
+    loop {
+        let now = Instant::now();
+        let playing = !player.paused && !player.finished;
+        if playing && now >= next_tick {
+            player.advance(player.fps.div_ceil(MAX_REDRAWS_PER_SECOND))?;
+            next_tick = now + Duration::from_secs(1) / player.fps.min(MAX_REDRAWS_PER_SECOND);
+        }
+        player.draw(title)?;
+        let wait = if playing {
+            next_tick.saturating_duration_since(Instant::now())
+        } else {
+            Duration::from_secs(60)
+        };
+        if !event::poll(wait)? {
+            continue;
+        }
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            ..
+        }) = event::read()?
+        else {
+            continue;
+        };
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(Playback::Quit),
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => {
+                return Ok(Playback::Quit)
+            }
+            _ if player.finished => return Ok(Playback::Finished),
+            KeyCode::Char(' ') => player.paused = !player.paused,
+            KeyCode::Right | KeyCode::Char('.') => {
+                player.paused = true;
+                player.advance(1)?;
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => {
+                player.fps = (player.fps * 2).min(MAX_FPS);
+            }
+            KeyCode::Char('-') | KeyCode::Down => player.fps = (player.fps / 2).max(1),
+            _ => {}
+        }
+    }
+}
+
+struct Player<I> {
+    frames: I,
+    frame: Option<Frame>,
+    /// How many frames have been taken so far.
+    shown: usize,
+    finished: bool,
+    paused: bool,
+    fps: u32,
+}
+
+impl<I: Iterator<Item = AocResult<Frame>>> Player<I> {
+    /// Moves `count` frames on, keeping only the last.
+    fn advance(&mut self, count: u32) -> AocResult<()> {
+        for _ in 0..count {
+            match self.frames.next().transpose()? {
+                Some(frame) => {
+                    self.frame = Some(frame);
+                    self.shown += 1;
+                }
+                None => {
+                    self.finished = true;
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn draw(&self, title: &str) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height.saturating_sub(HEADER_LINES) as usize);
+        let status = if self.finished {
+            "done, press any key".to_owned()
+        } else {
+            format!(
+                "{}, {} fps",
+                if self.paused { "paused" } else { "playing" },
+                self.fps
+            )
+        };
+        let mut out = io::stdout().lock();
+        let caption = self.frame.as_ref().map_or("", |frame| &frame.caption);
+        let header = [
+            format!("{}: {}", title, caption),
+            format!(
+                "frame {} ({})  [space] play/pause  [→] step  [+/-] speed  [q] quit",
+                self.shown, status
+            ),
+        ];
+        for (y, line) in (0..).zip(&header) {
+            queue!(out, MoveTo(0, y), Print(clip(line, 0, width)))?;
+            queue!(out, Clear(ClearType::UntilNewLine))?;
+        }
+        let mut next_line = HEADER_LINES;
+        if let Some(frame) = &self.frame {
+            let (focus_row, focus_col) = frame.focus.unwrap_or((0, 0));
+            let longest = frame.rows.iter().map(|row| row.chars().count()).max();
+            let first_row = window_start(frame.rows.len(), height, focus_row);
+            let first_col = window_start(longest.unwrap_or(0), width, focus_col);
+            for row in frame.rows.iter().skip(first_row).take(height) {
+                queue!(
+                    out,
+                    MoveTo(0, next_line),
+                    Print(clip(row, first_col, width))
+                )?;
+                queue!(out, Clear(ClearType::UntilNewLine))?;
+                next_line += 1;
+            }
+        }
+        queue!(out, MoveTo(0, next_line), Clear(ClearType::FromCursorDown))?;
+        out.flush()
+    }
+}
+
+/// The first of `len` rows (or columns) to show in `visible` of them, keeping `focus` as near
+/// the middle as the edges allow.
+fn window_start(len: usize, visible: usize, focus: usize) -> usize {
+    focus
+        .saturating_sub(visible / 2)
+        .min(len.saturating_sub(visible))
+}
+
+fn clip(line: &str, skip: usize, width: usize) -> String {
+    line.chars().skip(skip).take(width).collect()
+}
+
+/// Raw mode on the alternate screen, restored when dropped, even by a panic.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        // Past this point the terminal needs restoring, so make the guard before anything else
+        // can fail.
+        let screen = Screen;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_focus_in_view() {
+        // Small pictures aren't scrolled.
+        assert_eq!(window_start(10, 20, 9), 0);
+        // Big ones centre the focus...
+        assert_eq!(window_start(100, 20, 50), 40);
+        // ...unless that would scroll past an edge.
+        assert_eq!(window_start(100, 20, 3), 0);
+        assert_eq!(window_start(100, 20, 99), 80);
+        assert_eq!(clip("#..#.##", 2, 3), ".#.");
+    }
+}
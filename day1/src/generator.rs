@@ -0,0 +1,21 @@
+use common_utils::Generator;
+use rand::Rng;
+
+use crate::Day1;
+
+impl Generator for Day1 {
+    const SIZE_UNIT: &'static str = "elves";
+    const DEFAULT_SIZE: usize = 250;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let elves: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let items = rng.gen_range(1..=15);
+                (0..items)
+                    .map(|_| format!("{}\n", rng.gen_range(1000..=60000)))
+                    .collect()
+            })
+            .collect();
+        elves.join("\n")
+    }
+}
@@ -0,0 +1,49 @@
+use std::collections::BinaryHeap;
+use std::str::FromStr;
+
+use common_utils::{sections, Answer, AocResult, ParseDiagnostic, Solution};
+
+#[cfg(feature = "generator")]
+pub mod generator;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    /// Total calories carried by each elf.
+    type Parsed = Vec<u64>;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        sections(input)
+            .map(|elf| {
+                elf.lines()
+                    .zip(elf.first_line..)
+                    .map(|(line, line_no)| {
+                        u64::from_str(line).map_err(|_| {
+                            ParseDiagnostic::new(line_no, 1, line, "a calorie count").into()
+                        })
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> AocResult<Answer> {
+        Ok(max_total_calories(parsed.iter().copied()).into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> AocResult<Answer> {
+        Ok(top_three_total(parsed.iter().copied()).into())
+    }
+}
+
+pub fn max_total_calories(totals: impl IntoIterator<Item = u64>) -> u64 {
+    totals.into_iter().max().unwrap_or(0)
+}
+
+pub fn top_three_total(totals: impl IntoIterator<Item = u64>) -> u64 {
+    let mut heap: BinaryHeap<u64> = totals.into_iter().collect();
+    let top_three: [Option<u64>; 3] = [heap.pop(), heap.pop(), heap.pop()];
+    top_three.into_iter().flatten().sum::<u64>()
+}
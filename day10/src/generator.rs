@@ -0,0 +1,34 @@
+use common_utils::Generator;
+use rand::Rng;
+
+use crate::Day10;
+
+/// Cycles the CRT needs to draw its whole screen.
+const SCREEN_CYCLES: usize = 240;
+
+impl Generator for Day10 {
+    const SIZE_UNIT: &'static str = "instructions";
+    const DEFAULT_SIZE: usize = 146;
+
+    /// Each `addx` heads the sprite for a random column, so it stays on screen. The program is
+    /// padded with `noop`s until it lasts long enough to draw the whole screen.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let mut program = String::new();
+        let (mut x, mut cycles) = (1i32, 0);
+        for _ in 0..size {
+            if rng.gen_bool(0.3) {
+                program.push_str("noop\n");
+                cycles += 1;
+            } else {
+                let delta = rng.gen_range(0..40) - x;
+                program.push_str(&format!("addx {}\n", delta));
+                x += delta;
+                cycles += 2;
+            }
+        }
+        for _ in cycles..SCREEN_CYCLES {
+            program.push_str("noop\n");
+        }
+        program
+    }
+}
@@ -0,0 +1,103 @@
+use common_utils::{parse_lines, Answer, AocResult, Solution};
+use itertools::Itertools;
+
+#[cfg(feature = "generator")]
+pub mod generator;
+#[cfg(feature = "visualize")]
+pub mod visualize;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed = Vec<Instruction>;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        Ok(parse_lines(input, parser::instruction).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(instructions: &Self::Parsed) -> AocResult<Answer> {
+        let mut reg_values = run_program(instructions.iter().copied());
+        reg_values.by_ref().take(19).for_each(drop);
+        let signal_strength_sum = reg_values
+            .step_by(40)
+            .enumerate()
+            .map(|(i, val)| val * (20 + 40 * i as i32))
+            .sum::<i32>();
+        Ok(signal_strength_sum.into())
+    }
+
+    fn part2(instructions: &Self::Parsed) -> AocResult<Answer> {
+        let rows = run_program(instructions.iter().copied())
+            .chunks(40)
+            .into_iter()
+            .map(|chunk| {
+                chunk
+                    .enumerate()
+                    .map(|(i, val)| if pixel_lit(i, val) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect_vec();
+        Ok(Answer::Art(rows))
+    }
+}
+
+/// Whether the CRT draws a lit pixel at `column` while the three-wide sprite is centred on `x`.
+pub fn pixel_lit(column: usize, x: i32) -> bool {
+    (x - 1..=x + 1).contains(&(column as i32))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Instruction {
+    Add(i16),
+    Noop,
+}
+
+pub fn run_program(iter: impl IntoIterator<Item = Instruction>) -> impl Iterator<Item = i32> {
+    let mut value = 1i32;
+    let mut to_add = None;
+    iter.into_iter().batching(move |instructions| {
+        if let Some(x) = to_add {
+            to_add = None;
+            let old_value = value;
+            value += x as i32;
+            Some(old_value)
+        } else {
+            instructions.next().map(|instruction| {
+                if let Instruction::Add(x) = instruction {
+                    to_add = Some(x);
+                }
+                value
+            })
+        }
+    })
+}
+
+pub mod parser {
+    use super::*;
+
+    use common_utils::ParseResult;
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::i16 as nom_i16,
+        combinator::{map, value},
+        error::context,
+        sequence::preceded,
+    };
+
+    pub fn instruction(s: &str) -> ParseResult<'_, Instruction> {
+        context(
+            "instruction (noop or addx)",
+            alt((value(Instruction::Noop, tag("noop")), add)),
+        )(s)
+    }
+
+    pub fn add(s: &str) -> ParseResult<'_, Instruction> {
+        map(
+            preceded(tag("addx "), context("addx operand", nom_i16)),
+            Instruction::Add,
+        )(s)
+    }
+}
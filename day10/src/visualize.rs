@@ -0,0 +1,52 @@
+use common_utils::{AocResult, Frame, Part, Visualize};
+
+use crate::{pixel_lit, run_program, Day10};
+
+const SCREEN_WIDTH: usize = 40;
+
+impl Visualize for Day10 {
+    const FPS: u32 = 10;
+
+    /// The CRT after every cycle, with the sprite and the beam's column drawn underneath.
+    /// Part 1 keeps a running total of the signal strengths.
+    fn frames(
+        instructions: &Self::Parsed,
+        part: Part,
+    ) -> AocResult<impl Iterator<Item = AocResult<Frame>> + '_> {
+        let mut screen: Vec<Vec<u8>> = Vec::new();
+        let mut signal_strength_sum = 0;
+        let frames = run_program(instructions.iter().copied())
+            .enumerate()
+            .map(move |(i, x)| {
+                let cycle = i + 1;
+                let (row, col) = (i / SCREEN_WIDTH, i % SCREEN_WIDTH);
+                if row == screen.len() {
+                    screen.push(vec![b' '; SCREEN_WIDTH]);
+                }
+                screen[row][col] = if pixel_lit(col, x) { b'#' } else { b'.' };
+                let caption = match part {
+                    Part::One => {
+                        if cycle % SCREEN_WIDTH == 20 {
+                            signal_strength_sum += cycle as i32 * x;
+                        }
+                        format!(
+                            "cycle {}, X = {}, signal strength sum {}",
+                            cycle, x, signal_strength_sum
+                        )
+                    }
+                    Part::Two => format!("cycle {}, X = {}", cycle, x),
+                };
+                let sprite: String = (0..SCREEN_WIDTH)
+                    .map(|col| if pixel_lit(col, x) { '#' } else { ' ' })
+                    .collect();
+                let beam = format!("{:>width$}", "^", width = col + 1);
+                let rows = screen
+                    .iter()
+                    .map(|row| String::from_utf8_lossy(row).into_owned())
+                    .chain([String::new(), sprite, beam])
+                    .collect();
+                Ok(Frame::new(caption, rows).with_focus((row, col)))
+            });
+        Ok(frames)
+    }
+}
@@ -0,0 +1,115 @@
+use common_utils::Generator;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{Argument, Day11, Monkey, Operation, TestAndTargets};
+
+/// Test divisors, one per monkey. Their product stays under 2^32, so part 2 can square a
+/// reduced worry level without overflowing.
+const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// Attempts at a troop whose part 1 worry levels fit in a `u64` that may include `old * old`,
+/// then ones that may multiply by constants. Past that every operation is an addition, which
+/// part 1's division by 3 always keeps small.
+const SQUARING_ATTEMPTS: usize = 100;
+const MULTIPLYING_ATTEMPTS: usize = 200;
+
+impl Generator for Day11 {
+    const SIZE_UNIT: &'static str = "monkeys";
+    const DEFAULT_SIZE: usize = 8;
+
+    /// Clamps `size` to 2-9 monkeys.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let count = size.clamp(2, DIVISORS.len());
+        let monkeys = (0..)
+            .map(|attempt| troop(rng, count, attempt))
+            .find(|monkeys| part1_fits(monkeys.clone()))
+            .unwrap_or_default();
+        let descriptions: Vec<String> = monkeys.iter().enumerate().map(describe).collect();
+        descriptions.join("\n")
+    }
+}
+
+fn troop<R: Rng + ?Sized>(rng: &mut R, count: usize, attempt: usize) -> Vec<Monkey> {
+    let mut divisors = DIVISORS[..count].to_vec();
+    divisors.shuffle(rng);
+    let squarer = (attempt < SQUARING_ATTEMPTS).then(|| rng.gen_range(0..count));
+    let may_multiply = attempt < MULTIPLYING_ATTEMPTS;
+    divisors
+        .into_iter()
+        .enumerate()
+        .map(|(i, argument)| {
+            let op = if squarer == Some(i) {
+                (Operation::Mult, Argument::Old)
+            } else if may_multiply && rng.gen_bool(0.5) {
+                (Operation::Mult, Argument::Constant(rng.gen_range(2..=19)))
+            } else {
+                (Operation::Add, Argument::Constant(rng.gen_range(1..=8)))
+            };
+            let mut other = || (i + rng.gen_range(1..count)) % count;
+            let is_divisible = other();
+            let mut is_not_divisible = other();
+            if count > 2 {
+                while is_not_divisible == is_divisible {
+                    is_not_divisible = other();
+                }
+            }
+            Monkey {
+                items: (0..rng.gen_range(1..=8))
+                    .map(|_| rng.gen_range(50..=99))
+                    .collect(),
+                op,
+                test: TestAndTargets {
+                    argument,
+                    is_divisible,
+                    is_not_divisible,
+                },
+            }
+        })
+        .collect()
+}
+
+/// Whether part 1's 20 rounds get through without a worry level overflowing.
+fn part1_fits(mut monkeys: Vec<Monkey>) -> bool {
+    for _ in 0..20 {
+        for i in 0..monkeys.len() {
+            let Monkey { op, test, .. } = monkeys[i];
+            for old in std::mem::take(&mut monkeys[i].items) {
+                let Ok(new) = op.0.run(old, op.1) else {
+                    return false;
+                };
+                let new = new / 3;
+                monkeys[test.get_next_monkey(new)].items.push(new);
+            }
+        }
+    }
+    true
+}
+
+fn describe((i, monkey): (usize, &Monkey)) -> String {
+    let items: Vec<String> = monkey.items.iter().map(u64::to_string).collect();
+    let operator = match monkey.op.0 {
+        Operation::Add => '+',
+        Operation::Mult => '*',
+    };
+    let operand = match monkey.op.1 {
+        Argument::Constant(x) => x.to_string(),
+        Argument::Old => "old".to_owned(),
+    };
+    format!(
+        "Monkey {}:
+  Starting items: {}
+  Operation: new = old {} {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+",
+        i,
+        items.join(", "),
+        operator,
+        operand,
+        monkey.test.argument,
+        monkey.test.is_divisible,
+        monkey.test.is_not_divisible
+    )
+}
@@ -0,0 +1,310 @@
+use std::collections::BinaryHeap;
+
+use common_utils::{sections, Answer, AocError, AocResult, Solution};
+use tap::tap::Tap;
+
+#[cfg(feature = "generator")]
+pub mod generator;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed = Vec<Monkey>;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        let monkeys: Vec<Monkey> = sections(input)
+            .map(|paragraph| paragraph.parse(parser::monkey))
+            .collect::<Result<_, _>>()?;
+        for (i, monkey) in monkeys.iter().enumerate() {
+            let test = &monkey.test;
+            if test.argument == 0 {
+                return Err(AocError::invalid_state(format!(
+                    "Monkey {} tests for divisibility by 0",
+                    i
+                )));
+            }
+            for target in [test.is_divisible, test.is_not_divisible] {
+                if target >= monkeys.len() {
+                    return Err(AocError::invalid_state(format!(
+                        "Monkey {} throws to monkey {}, but there are only {} monkeys",
+                        i,
+                        target,
+                        monkeys.len()
+                    )));
+                }
+            }
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Parsed) -> AocResult<Answer> {
+        let times_inspecting = get_times_inspecting(&mut monkeys.clone(), 20, Reducer::Part1)?;
+        Ok(monkey_business(times_inspecting).into())
+    }
+
+    fn part2(monkeys: &Self::Parsed) -> AocResult<Answer> {
+        let big_modulo = monkeys
+            .iter()
+            .map(|monkey| monkey.test.argument)
+            .try_fold(1u64, |prod, val| prod.checked_mul(val))
+            .ok_or_else(|| AocError::invalid_state("Product of test arguments overflowed"))?;
+        let times_inspecting =
+            get_times_inspecting(&mut monkeys.clone(), 10000, Reducer::Part2(big_modulo))?;
+        Ok(monkey_business(times_inspecting).into())
+    }
+}
+
+pub fn monkey_business(times_inspecting: Vec<usize>) -> u64 {
+    times_inspecting
+        .into_iter()
+        .collect::<BinaryHeap<_>>()
+        .into_iter()
+        .take(2)
+        .map(|x| x as u64)
+        .product::<u64>()
+}
+
+/// Every throw target must be one of `monkeys`, as [`Day11::parse`] checks.
+pub fn get_times_inspecting(
+    monkeys: &mut [Monkey],
+    rounds: usize,
+    reducer: Reducer,
+) -> AocResult<Vec<usize>> {
+    let mut times_inspecting = vec![0; monkeys.len()];
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            monkeys[i]
+                .run_round(reducer)
+                .collect::<AocResult<Vec<_>>>()?
+                .tap(|vec| times_inspecting[i] += vec.len())
+                .into_iter()
+                .for_each(|(j, val)| {
+                    tracing::debug!(from = i, to = j, worry = val, "monkey throw");
+                    monkeys[j].add_item(val)
+                });
+        }
+    }
+
+    Ok(times_inspecting)
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub items: Vec<u64>,
+    pub op: (Operation, Argument),
+    pub test: TestAndTargets,
+}
+
+impl Monkey {
+    pub fn run_round(
+        &'_ mut self,
+        reducer: Reducer,
+    ) -> impl Iterator<Item = AocResult<(usize, u64)>> + '_ {
+        let ((op, arg), test) = (self.op, self.test);
+        self.items.drain(..).map(move |old| {
+            let new = reducer.reduce(op.run(old, arg)?);
+            Ok((test.get_next_monkey(new), new))
+        })
+    }
+
+    pub fn add_item(&mut self, item: u64) {
+        self.items.push(item);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Reducer {
+    Part1,
+    Part2(u64),
+}
+
+impl Reducer {
+    pub fn reduce(self, new: u64) -> u64 {
+        match self {
+            Reducer::Part1 => new.div_euclid(3),
+            Reducer::Part2(modulo) => new % modulo,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operation {
+    Add,
+    Mult,
+}
+
+impl Operation {
+    pub fn run(self, old: u64, arg: Argument) -> AocResult<u64> {
+        let arg = arg.into_value(old);
+        match self {
+            Operation::Add => old.checked_add(arg),
+            Operation::Mult => old.checked_mul(arg),
+        }
+        .ok_or_else(|| {
+            AocError::invalid_state(format!(
+                "Worry level overflowed at {} {:?} {}",
+                old, self, arg
+            ))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Argument {
+    Constant(u64),
+    Old,
+}
+
+impl Argument {
+    pub fn into_value(self, old: u64) -> u64 {
+        match self {
+            Argument::Constant(x) => x,
+            Argument::Old => old,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TestAndTargets {
+    pub argument: u64,
+    pub is_divisible: usize,
+    pub is_not_divisible: usize,
+}
+
+impl TestAndTargets {
+    pub fn get_next_monkey(&self, new_val: u64) -> usize {
+        if new_val.is_multiple_of(self.argument) {
+            self.is_divisible
+        } else {
+            self.is_not_divisible
+        }
+    }
+}
+
+pub mod parser {
+    use super::*;
+
+    use common_utils::ParseResult;
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{line_ending, u64 as nom_u64},
+        combinator::{cut, map, value},
+        error::context,
+        multi::separated_list0,
+        sequence::{delimited, preceded, separated_pair, tuple},
+    };
+
+    /// Once a header matches, the rest of the paragraph must too, so errors point inside it.
+    pub fn monkey(s: &str) -> ParseResult<'_, Monkey> {
+        preceded(
+            context(
+                "monkey header",
+                delimited(tag("Monkey "), nom_u64, tag(":")),
+            ),
+            cut(map(
+                tuple((
+                    preceded(
+                        line_ending,
+                        context(
+                            "starting items",
+                            preceded(
+                                tag("  Starting items: "),
+                                separated_list0(tag(", "), nom_u64),
+                            ),
+                        ),
+                    ),
+                    preceded(
+                        line_ending,
+                        context(
+                            "operation",
+                            preceded(tag("  Operation: new = old "), operation),
+                        ),
+                    ),
+                    preceded(line_ending, test),
+                )),
+                |(items, op, test)| Monkey { items, op, test },
+            )),
+        )(s)
+    }
+
+    pub fn operation(s: &str) -> ParseResult<'_, (Operation, Argument)> {
+        separated_pair(
+            alt((
+                value(Operation::Add, tag("+")),
+                value(Operation::Mult, tag("*")),
+            )),
+            tag(" "),
+            alt((
+                value(Argument::Old, tag("old")),
+                map(nom_u64, Argument::Constant),
+            )),
+        )(s)
+    }
+
+    pub fn test(s: &str) -> ParseResult<'_, TestAndTargets> {
+        map(
+            tuple((
+                context(
+                    "divisibility test",
+                    preceded(tag("  Test: divisible by "), nom_u64),
+                ),
+                preceded(
+                    line_ending,
+                    context(
+                        "true target",
+                        preceded(tag("    If true: throw to monkey "), nom_u64),
+                    ),
+                ),
+                preceded(
+                    line_ending,
+                    context(
+                        "false target",
+                        preceded(tag("    If false: throw to monkey "), nom_u64),
+                    ),
+                ),
+            )),
+            |(argument, is_divisible, is_not_divisible)| TestAndTargets {
+                argument,
+                is_divisible: is_divisible as usize,
+                is_not_divisible: is_not_divisible as usize,
+            },
+        )(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONKEY: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * old
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+
+    #[test]
+    fn rejects_troops_that_cant_be_simulated() {
+        let stray_target =
+            MONKEY.replace("If true: throw to monkey 0", "If true: throw to monkey 7");
+        assert!(matches!(
+            Day11::parse(&stray_target),
+            Err(AocError::InvalidState(_))
+        ));
+        let zero_divisor = MONKEY.replace("divisible by 23", "divisible by 0");
+        assert!(matches!(
+            Day11::parse(&zero_divisor),
+            Err(AocError::InvalidState(_))
+        ));
+        // Squaring every round overflows long before round 20.
+        let monkeys = Day11::parse(MONKEY).unwrap();
+        assert!(matches!(
+            Day11::part1(&monkeys),
+            Err(AocError::InvalidState(_))
+        ));
+    }
+}
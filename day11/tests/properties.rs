@@ -0,0 +1,86 @@
+//! Checks that keeping worry levels modulo the product of the test divisors changes nothing,
+//! against exact arithmetic on numbers that are never reduced.
+
+use day11::{get_times_inspecting, Argument, Monkey, Operation, Reducer, TestAndTargets};
+use num_bigint::BigUint;
+use proptest::prelude::*;
+
+/// Part 2's rules with exact worry levels.
+fn naive_times_inspecting(monkeys: &[Monkey], rounds: usize) -> Vec<usize> {
+    let mut items: Vec<Vec<BigUint>> = monkeys
+        .iter()
+        .map(|monkey| monkey.items.iter().map(|&item| item.into()).collect())
+        .collect();
+    let mut times_inspecting = vec![0; monkeys.len()];
+    for _ in 0..rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for old in std::mem::take(&mut items[i]) {
+                times_inspecting[i] += 1;
+                let arg = match monkey.op.1 {
+                    Argument::Constant(x) => BigUint::from(x),
+                    Argument::Old => old.clone(),
+                };
+                let new = match monkey.op.0 {
+                    Operation::Add => old + arg,
+                    Operation::Mult => old * arg,
+                };
+                let target = if (&new % monkey.test.argument) == BigUint::from(0u8) {
+                    monkey.test.is_divisible
+                } else {
+                    monkey.test.is_not_divisible
+                };
+                items[target].push(new);
+            }
+        }
+    }
+    times_inspecting
+}
+
+fn troop() -> impl Strategy<Value = Vec<Monkey>> {
+    (2usize..=6).prop_flat_map(|count| {
+        let op = prop_oneof![
+            (0u64..10).prop_map(|x| (Operation::Add, Argument::Constant(x))),
+            (1u64..20).prop_map(|x| (Operation::Mult, Argument::Constant(x))),
+            Just((Operation::Add, Argument::Old)),
+        ];
+        let monkey = (
+            prop::collection::vec(0u64..100, 0..5),
+            op,
+            2u64..=23,
+            1..count,
+            1..count,
+        );
+        // At most one monkey squares, so an item is squared at most once a round and exact worry
+        // levels stay a manageable size.
+        let squarer = prop::option::of(0..count);
+        (prop::collection::vec(monkey, count), squarer).prop_map(move |(monkeys, squarer)| {
+            monkeys
+                .into_iter()
+                .enumerate()
+                .map(|(i, (items, op, argument, yes, no))| Monkey {
+                    items,
+                    op: if squarer == Some(i) {
+                        (Operation::Mult, Argument::Old)
+                    } else {
+                        op
+                    },
+                    test: TestAndTargets {
+                        argument,
+                        is_divisible: (i + yes) % count,
+                        is_not_divisible: (i + no) % count,
+                    },
+                })
+                .collect()
+        })
+    })
+}
+
+proptest! {
+    // Squaring doubles an exact worry level's digits, so keep the runs short.
+    #[test]
+    fn reduced_worry_matches_exact(monkeys in troop(), rounds in 1usize..=10) {
+        let modulo = monkeys.iter().map(|monkey| monkey.test.argument).product();
+        let fast = get_times_inspecting(&mut monkeys.clone(), rounds, Reducer::Part2(modulo))?;
+        prop_assert_eq!(fast, naive_times_inspecting(&monkeys, rounds));
+    }
+}
@@ -0,0 +1,46 @@
+use common_utils::Generator;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Day12;
+
+/// Steps from `a` to `z`; any path from start to end is at least this long.
+const CLIMB: usize = 25;
+
+impl Generator for Day12 {
+    const SIZE_UNIT: &'static str = "columns";
+    const DEFAULT_SIZE: usize = 160;
+
+    /// Random heights with one gentle path carved from corner to corner, so the summit is always
+    /// reachable. There are a quarter as many rows as columns, and at least 26 columns.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let cols = size.max(CLIMB + 1);
+        let rows = (cols / 4).max(1);
+        let mut map: Vec<Vec<u8>> = (0..rows)
+            .map(|_| (0..cols).map(|_| rng.gen_range(b'a'..=b'z')).collect())
+            .collect();
+
+        let mut steps = vec![(1, 0); rows - 1];
+        steps.extend(vec![(0, 1); cols - 1]);
+        steps.shuffle(rng);
+        let path_len = steps.len();
+        let (mut row, mut col) = (0, 0);
+        map[row][col] = b'S';
+        for (i, (down, right)) in steps.into_iter().enumerate() {
+            row += down;
+            col += right;
+            map[row][col] = b'a' + ((i + 1) * CLIMB / path_len) as u8;
+        }
+        map[row][col] = b'E';
+
+        if rng.gen_bool(0.5) {
+            map.reverse();
+        }
+        if rng.gen_bool(0.5) {
+            map.iter_mut().for_each(|line| line.reverse());
+        }
+        map.into_iter()
+            .flat_map(|line| line.into_iter().map(char::from).chain(Some('\n')))
+            .collect()
+    }
+}
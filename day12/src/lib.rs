@@ -0,0 +1,76 @@
+use common_utils::{Answer, AocError, AocResult, Grid, Position, Solution};
+
+#[cfg(feature = "generator")]
+pub mod generator;
+#[cfg(feature = "render")]
+pub mod render;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Parsed = HeightMap;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        let mut start_position: Option<Position> = None;
+        let mut end_position: Option<Position> = None;
+        let data = Grid::parse(input, |pos, c| match c {
+            'S' => {
+                start_position = Some(pos);
+                Ok(0)
+            }
+            'E' => {
+                end_position = Some(pos);
+                Ok(b'z' - b'a')
+            }
+            'a'..='z' => Ok(c as u8 - b'a'),
+            _ => Err("a height from a to z, S or E"),
+        })?;
+        Ok(HeightMap {
+            data,
+            start: start_position
+                .ok_or_else(|| AocError::invalid_state("No start position in the map"))?,
+            end: end_position
+                .ok_or_else(|| AocError::invalid_state("No end position in the map"))?,
+        })
+    }
+
+    fn part1(map: &Self::Parsed) -> AocResult<Answer> {
+        Ok(map.shortest_descent(|pos| pos == map.start)?.into())
+    }
+
+    fn part2(map: &Self::Parsed) -> AocResult<Answer> {
+        Ok(map.shortest_descent(|pos| map.data[pos] == 0)?.into())
+    }
+}
+
+pub struct HeightMap {
+    pub data: Grid<u8>,
+    pub start: Position,
+    pub end: Position,
+}
+
+impl HeightMap {
+    /// Walks backwards from the end, so the climbing rule is reversed.
+    pub fn shortest_descent(&self, is_target: impl Fn(Position) -> bool) -> AocResult<usize> {
+        Ok(self.descent_path(is_target)?.len() - 1)
+    }
+
+    /// The positions on a shortest path from the end down to a target, both included.
+    pub fn descent_path(&self, is_target: impl Fn(Position) -> bool) -> AocResult<Vec<Position>> {
+        let data = &self.data;
+        let path = pathfinding::directed::dijkstra::dijkstra(
+            &self.end,
+            |&pos| {
+                let curr_height = data[pos];
+                data.neighbours4(pos)
+                    .filter(move |&new_pos| curr_height <= data[new_pos] + 1)
+                    .map(|new_pos| (new_pos, 1))
+            },
+            |&pos| is_target(pos),
+        )
+        .ok_or_else(|| AocError::no_solution("Could not find any path to the target"))?;
+        Ok(path.0)
+    }
+}
@@ -0,0 +1,61 @@
+use common_utils::{AocResult, Part, Picture, Position, Render, Rgb};
+
+use crate::{Day12, HeightMap};
+
+/// Palette indices after the 26 heights.
+const PATH: u8 = 26;
+const START: u8 = 27;
+const END: u8 = 28;
+
+/// How many pictures an animation of the climb has, about.
+const CLIMB_FRAMES: usize = 60;
+
+impl Render for Day12 {
+    /// The height map, with a shortest climb drawn on it.
+    fn picture(map: &Self::Parsed, part: Part) -> AocResult<Picture> {
+        let climb = climb(map, part)?;
+        Ok(draw(map, &climb))
+    }
+
+    /// The climb drawn a stretch at a time, from where it starts to the end.
+    fn pictures(
+        map: &Self::Parsed,
+        part: Part,
+    ) -> AocResult<impl Iterator<Item = AocResult<Picture>> + '_> {
+        let climb = climb(map, part)?;
+        let stride = (climb.len() / CLIMB_FRAMES).max(1);
+        let lengths = (1..climb.len()).step_by(stride).chain([climb.len()]);
+        Ok(lengths.map(move |length| Ok(draw(map, &climb[..length]))))
+    }
+}
+
+/// A shortest path, starting from the trailhead and ending at the end.
+fn climb(map: &HeightMap, part: Part) -> AocResult<Vec<Position>> {
+    let mut path = match part {
+        Part::One => map.descent_path(|pos| pos == map.start)?,
+        Part::Two => map.descent_path(|pos| map.data[pos] == 0)?,
+    };
+    path.reverse();
+    Ok(path)
+}
+
+/// Heights from dark green up to white, `path` in red, and the start and end of the climb in
+/// blue and yellow.
+fn draw(map: &HeightMap, path: &[Position]) -> Picture {
+    let mut palette: Vec<Rgb> = (0..26)
+        .map(|height| {
+            let shade = height * 9;
+            [20 + shade, 60 + shade / 4 * 3, 20 + shade]
+        })
+        .collect();
+    palette.extend([[220, 30, 30], [40, 90, 230], [250, 210, 40]]);
+    let mut picture = Picture::from_grid(&map.data, palette, |_, &height| height);
+    for &position in path {
+        picture.pixels[position] = PATH;
+    }
+    if let (Some(&first), Some(&last)) = (path.first(), path.last()) {
+        picture.pixels[first] = START;
+        picture.pixels[last] = END;
+    }
+    picture
+}
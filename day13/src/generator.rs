@@ -0,0 +1,53 @@
+use std::ops::ControlFlow;
+
+use common_utils::Generator;
+use rand::Rng;
+
+use crate::{Day13, IntOrVec};
+
+/// How deep lists nest inside a packet.
+const MAX_DEPTH: usize = 4;
+
+impl Generator for Day13 {
+    const SIZE_UNIT: &'static str = "pairs";
+    const DEFAULT_SIZE: usize = 150;
+
+    /// Pairs the ordering rules can't decide between are thrown away, so every pair has an
+    /// answer.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let pairs: Vec<String> = (0..size.max(1))
+            .map(|_| loop {
+                let (left, right) = (list(rng, 0), list(rng, 0));
+                if let ControlFlow::Break(_) = left.correct_order(&right) {
+                    break format!("{}\n{}\n", render(&left), render(&right));
+                }
+            })
+            .collect();
+        pairs.join("\n")
+    }
+}
+
+fn list<R: Rng + ?Sized>(rng: &mut R, depth: usize) -> IntOrVec {
+    let len = rng.gen_range(0..=5);
+    IntOrVec::Vec(
+        (0..len)
+            .map(|_| {
+                if depth < MAX_DEPTH && rng.gen_bool(0.3) {
+                    list(rng, depth + 1)
+                } else {
+                    IntOrVec::Int(rng.gen_range(0..=10))
+                }
+            })
+            .collect(),
+    )
+}
+
+fn render(packet: &IntOrVec) -> String {
+    match packet {
+        IntOrVec::Int(x) => x.to_string(),
+        IntOrVec::Vec(items) => {
+            let items: Vec<String> = items.iter().map(render).collect();
+            format!("[{}]", items.join(","))
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::ops::ControlFlow;
+
+use common_utils::{sections, Answer, AocError, AocResult, ParseResult, Solution};
+use itertools::{EitherOrBoth, Itertools};
+use nom::{
+    branch::alt,
+    character::complete::char as nom_char,
+    character::complete::line_ending,
+    character::complete::u32 as nom_u32,
+    combinator::map,
+    error::context,
+    multi::separated_list0,
+    sequence::{delimited, separated_pair},
+};
+use tap::Tap;
+
+#[cfg(feature = "generator")]
+pub mod generator;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Parsed = Vec<(IntOrVec, IntOrVec)>;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        Ok(sections(input)
+            .map(|pair| pair.parse(packet_pair))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(pairs: &Self::Parsed) -> AocResult<Answer> {
+        let index_sum = pairs
+            .iter()
+            .enumerate()
+            .map(|(i, val)| (i + 1, val))
+            .filter(|(i, (a, b))| match a.correct_order(b) {
+                ControlFlow::Continue(_) => {
+                    tracing::warn!(
+                        pair = i,
+                        "comparison ended undecided, counting it as ordered"
+                    );
+                    true
+                }
+                ControlFlow::Break(val) => val,
+            })
+            .map(|(i, _)| i)
+            .sum::<usize>();
+        Ok(index_sum.into())
+    }
+
+    fn part2(pairs: &Self::Parsed) -> AocResult<Answer> {
+        let divider1 = IntOrVec::Vec(vec![IntOrVec::Vec(vec![IntOrVec::Int(2)])]);
+        let divider2 = IntOrVec::Vec(vec![IntOrVec::Vec(vec![IntOrVec::Int(6)])]);
+
+        let ordered_packets = pairs
+            .iter()
+            .cloned()
+            .flat_map(|(a, b)| [a, b])
+            .chain([divider1.clone(), divider2.clone()])
+            .collect_vec()
+            .tap_mut(|v| v.sort_unstable());
+        let position = |divider: &IntOrVec| {
+            ordered_packets
+                .binary_search(divider)
+                .map(|i| i + 1)
+                .map_err(|_| AocError::invalid_state("Divider packet lost while sorting"))
+        };
+        let (pos1, pos2) = (position(&divider1)?, position(&divider2)?);
+        Ok((pos1 * pos2).into())
+    }
+}
+
+/// A pair of packets on consecutive lines.
+pub fn packet_pair(s: &str) -> ParseResult<'_, (IntOrVec, IntOrVec)> {
+    separated_pair(IntOrVec::parse_list, line_ending, IntOrVec::parse_list)(s)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntOrVec {
+    Int(u32),
+    Vec(Vec<IntOrVec>),
+}
+
+/// Packet order, with packets the puzzle can't tell apart (like `[2]` and `[[2]]`) ordered by
+/// shape so sorting never has to give up.
+impl Ord for IntOrVec {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use std::cmp::Ordering::*;
+        match self.correct_order(other) {
+            ControlFlow::Continue(_) => self.shape_cmp(other),
+            ControlFlow::Break(b) => {
+                if b {
+                    Less
+                } else {
+                    Greater
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for IntOrVec {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl IntOrVec {
+    /// A total order that agrees with `==`: integers before lists, then by value.
+    fn shape_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use IntOrVec::*;
+        match (self, other) {
+            (Int(a), Int(b)) => a.cmp(b),
+            (Int(_), Vec(_)) => std::cmp::Ordering::Less,
+            (Vec(_), Int(_)) => std::cmp::Ordering::Greater,
+            (Vec(a), Vec(b)) => a
+                .iter()
+                .zip_longest(b)
+                .map(|pair| match pair {
+                    EitherOrBoth::Both(a, b) => a.shape_cmp(b),
+                    EitherOrBoth::Left(_) => std::cmp::Ordering::Greater,
+                    EitherOrBoth::Right(_) => std::cmp::Ordering::Less,
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal),
+        }
+    }
+
+    pub fn correct_order(&self, other: &IntOrVec) -> ControlFlow<bool, ()> {
+        use IntOrVec::*;
+
+        fn compare_iters<'a, 'b, A, B>(a: A, b: B) -> ControlFlow<bool, ()>
+        where
+            A: IntoIterator<Item = &'a IntOrVec>,
+            B: IntoIterator<Item = &'b IntOrVec>,
+        {
+            a.into_iter()
+                .zip_longest(b)
+                .try_fold((), |_, pair| match pair {
+                    EitherOrBoth::Both(a, b) => a.correct_order(b),
+                    EitherOrBoth::Left(_) => ControlFlow::Break(false),
+                    EitherOrBoth::Right(_) => ControlFlow::Break(true),
+                })
+        }
+
+        match (self, other) {
+            (Int(a), Int(b)) => match a.cmp(b) {
+                std::cmp::Ordering::Less => ControlFlow::Break(true),
+                std::cmp::Ordering::Equal => ControlFlow::Continue(()),
+                std::cmp::Ordering::Greater => ControlFlow::Break(false),
+            },
+            (Vec(a), Vec(b)) => compare_iters(a, b),
+            (elt @ Int(_), Vec(b)) => compare_iters(std::iter::once(elt), b),
+            (Vec(a), elt @ Int(_)) => compare_iters(a, std::iter::once(elt)),
+        }
+    }
+
+    pub fn parse_list(s: &str) -> ParseResult<'_, Self> {
+        context(
+            "packet list",
+            map(
+                delimited(
+                    nom_char('['),
+                    separated_list0(
+                        nom_char(','),
+                        alt((map(nom_u32, IntOrVec::Int), Self::parse_list)),
+                    ),
+                    nom_char(']'),
+                ),
+                IntOrVec::Vec,
+            ),
+        )(s)
+    }
+}
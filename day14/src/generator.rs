@@ -0,0 +1,45 @@
+use common_utils::{Generator, Point2};
+use rand::Rng;
+
+use crate::{Day14, SOURCE};
+
+/// Deepest a rock path starts, like the real scans.
+const MAX_DEPTH: i32 = 170;
+
+impl Generator for Day14 {
+    const SIZE_UNIT: &'static str = "rock paths";
+    const DEFAULT_SIZE: usize = 150;
+
+    /// Paths of alternating horizontal and vertical lines. The cave widens with `size`, a third
+    /// of a column per path on each side of the source, up to 450.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let paths = size.max(1);
+        let spread = (paths / 3 + 20).min(450) as i32;
+        let source_x = SOURCE.x as i32;
+        (0..paths)
+            .map(|_| {
+                let mut point = Point2::new(
+                    rng.gen_range(source_x - spread..=source_x + spread),
+                    rng.gen_range(10..=MAX_DEPTH),
+                );
+                let mut horizontal = rng.gen_bool(0.5);
+                let mut corners = vec![point];
+                for _ in 0..rng.gen_range(1..=5) {
+                    let length = rng.gen_range(1..=8) * if rng.gen_bool(0.5) { 1 } else { -1 };
+                    if horizontal {
+                        point.x += length;
+                    } else {
+                        point.y = (point.y + length).max(1);
+                    }
+                    horizontal = !horizontal;
+                    corners.push(point);
+                }
+                let corners: Vec<String> = corners
+                    .iter()
+                    .map(|corner| format!("{},{}", corner.x, corner.y))
+                    .collect();
+                corners.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
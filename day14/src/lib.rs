@@ -0,0 +1,197 @@
+use std::iter;
+use std::ops::ControlFlow;
+
+use common_utils::{
+    parse_lines, Answer, AocError, AocResult, BoundingBox, Grid, ParseResult, Point2, Position,
+    Solution,
+};
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::char as nom_char,
+    character::complete::u16 as nom_u16,
+    combinator::{cut, map},
+    error::context,
+    multi::separated_list1,
+    sequence::separated_pair,
+};
+use tap::Tap;
+
+#[cfg(feature = "generator")]
+pub mod generator;
+#[cfg(feature = "render")]
+pub mod render;
+#[cfg(feature = "visualize")]
+pub mod visualize;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Parsed = Vec<Vec<Point2<u16>>>;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        Ok(parse_lines(input, rock_path).try_collect()?)
+    }
+
+    fn part1(paths: &Self::Parsed) -> AocResult<Answer> {
+        Ok(count_grains(paths, false)?.into())
+    }
+
+    fn part2(paths: &Self::Parsed) -> AocResult<Answer> {
+        Ok(count_grains(paths, true)?.into())
+    }
+}
+
+pub const SOURCE: Point2<u16> = Point2::new(500, 0);
+
+/// Drops sand until it either falls into the abyss or, with a floor, blocks the source.
+pub fn count_grains(paths: &[Vec<Point2<u16>>], has_floor: bool) -> AocResult<usize> {
+    let (grid, source) = build_cave(paths, has_floor)?;
+    settle_sand(grid, source).try_fold(0, |count, grain| grain.map(|_| count + 1))
+}
+
+/// More cells than any real cave needs; a grid this big already takes a quarter of a gigabyte.
+const MAX_CELLS: usize = 1 << 28;
+
+/// The cave's rock, plus the floor if there is one, and where the sand comes in. The grid is
+/// wide enough that no grain can roll off either side.
+pub fn build_cave(
+    paths: &[Vec<Point2<u16>>],
+    has_floor: bool,
+) -> AocResult<(Grid<bool>, Position)> {
+    let rocks = BoundingBox::from_points(paths.iter().flatten().copied())
+        .ok_or_else(|| AocError::invalid_state("No rock paths given"))?;
+    let floor_y = usize::from(rocks.max.y) + 2;
+    let left = usize::from(rocks.min.x.min(SOURCE.x));
+    let right = usize::from(rocks.max.x.max(SOURCE.x));
+    // A pile resting on the floor spreads at most `floor_y` to either side of the source, which
+    // can be left of x = 0, so column 0 of the grid is x = left - floor_y.
+    let to_position = |point: Point2<u16>| {
+        Position::from(Point2::new(
+            usize::from(point.x) + floor_y - left,
+            usize::from(point.y),
+        ))
+    };
+    let grid_rows = floor_y + 1;
+    let grid_cols = right - left + 2 * floor_y + 1;
+    grid_rows
+        .checked_mul(grid_cols)
+        .filter(|&cells| cells <= MAX_CELLS)
+        .ok_or_else(|| {
+            AocError::invalid_state(format!(
+                "A {}x{} cave is too big to simulate",
+                grid_cols, grid_rows
+            ))
+        })?;
+    let grid = Grid::new(grid_rows, grid_cols, false).tap_mut(|mut_grid| {
+        paths.iter().for_each(|path| {
+            path.iter().copied().reduce(|start, end| {
+                let mut segment = BoundingBox {
+                    min: start,
+                    max: start,
+                };
+                segment.include(end);
+                for y in segment.min.y..=segment.max.y {
+                    for x in segment.min.x..=segment.max.x {
+                        mut_grid[to_position(Point2::new(x, y))] = true;
+                    }
+                }
+                end
+            });
+        });
+        if has_floor {
+            mut_grid.row_mut(floor_y).fill(true);
+        }
+    });
+    Ok((grid, to_position(SOURCE)))
+}
+
+/// Where each grain comes to rest, in order, stopping when one falls into the abyss, a grain
+/// comes to rest on the source, or one rolls off the side of the grid.
+pub fn settle_sand(
+    mut grid: Grid<bool>,
+    source: Position,
+) -> impl Iterator<Item = AocResult<Position>> {
+    let mut count = 0;
+    let mut done = false;
+    iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let position = match drop_sand(&grid, source) {
+            Ok(position) => position?,
+            Err(e) => {
+                done = true;
+                return Some(Err(e));
+            }
+        };
+        grid[position] = true;
+        count += 1;
+        tracing::debug!(grain = count, ?position, "sand came to rest");
+        done = position == source;
+        Some(Ok(position))
+    })
+}
+
+pub fn rock_path(s: &str) -> ParseResult<'_, Vec<Point2<u16>>> {
+    separated_list1(
+        tag(" -> "),
+        cut(context(
+            "X,Y point",
+            map(separated_pair(nom_u16, nom_char(','), nom_u16), |(x, y)| {
+                Point2::new(x, y)
+            }),
+        )),
+    )(s)
+}
+
+/// Where a grain dropped from `source` comes to rest, or `None` if it falls off the bottom.
+/// Rolling off either side is an error, since the grid must be wide enough to hold it.
+pub fn drop_sand(
+    grid: &Grid<bool>,
+    (source_row, source_col): Position,
+) -> AocResult<Option<Position>> {
+    let res = (source_row..grid.rows() - 1).try_fold(source_col, |col, row| {
+        let next_row = grid.row(row + 1);
+        // Straight down, then down-left, then down-right.
+        for next_col in [Some(col), col.checked_sub(1), Some(col + 1)] {
+            let Some((next_col, &blocked)) =
+                next_col.and_then(|next_col| Some((next_col, next_row.get(next_col)?)))
+            else {
+                return ControlFlow::Break(Err(AocError::invalid_state(format!(
+                    "Sand rolled off the side of the cave below row {}",
+                    row
+                ))));
+            };
+            if !blocked {
+                return ControlFlow::Continue(next_col);
+            }
+        }
+        ControlFlow::Break(Ok((row, col)))
+    });
+    match res {
+        ControlFlow::Break(position) => position.map(Some),
+        ControlFlow::Continue(_) => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caves_reach_past_the_edges_of_u16() -> AocResult<()> {
+        // The pile on a floor this deep spreads left of x = 0.
+        let deep = Day14::parse("500,1000 -> 502,1000")?;
+        let (grid, source) = build_cave(&deep, true)?;
+        assert_eq!(source, (0, 1002));
+        assert_eq!(grid.cols(), 2 * 1002 + 3);
+        // Rock at the far right of the range still gets a floor beyond it.
+        let far = Day14::parse("65535,10 -> 65535,12")?;
+        assert_eq!(count_grains(&far, false)?, 0);
+        assert_eq!(count_grains(&far, true)?, 14 * 14);
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use std::ops::RangeInclusive;
+
+use common_utils::{AocResult, Grid, Part, Picture, Point2, Position, Render, Rgb};
+
+use crate::{build_cave, settle_sand, Day14};
+
+const AIR: u8 = 0;
+const ROCK: u8 = 1;
+const SAND: u8 = 2;
+const SOURCE: u8 = 3;
+const PALETTE: [Rgb; 4] = [[20, 20, 30], [110, 100, 90], [230, 190, 90], [230, 40, 40]];
+
+/// How many pictures an animation of the pile growing has, about.
+const SAND_FRAMES: usize = 100;
+
+impl Render for Day14 {
+    /// The cave once the sand has stopped.
+    fn picture(paths: &Self::Parsed, part: Part) -> AocResult<Picture> {
+        let cave = SettledCave::new(paths, part)?;
+        Ok(cave.draw(cave.grains.len()))
+    }
+
+    /// The pile growing, from the empty cave to where the sand stops.
+    fn pictures(
+        paths: &Self::Parsed,
+        part: Part,
+    ) -> AocResult<impl Iterator<Item = AocResult<Picture>> + '_> {
+        let cave = SettledCave::new(paths, part)?;
+        let total = cave.grains.len();
+        let counts = (0..total)
+            .step_by((total / SAND_FRAMES).max(1))
+            .chain([total]);
+        Ok(counts.map(move |count| Ok(cave.draw(count))))
+    }
+}
+
+struct SettledCave {
+    rocks: Grid<bool>,
+    source: Position,
+    /// Where each grain came to rest, in order.
+    grains: Vec<Position>,
+    /// The columns with anything in them, and one either side.
+    cols: RangeInclusive<usize>,
+}
+
+impl SettledCave {
+    fn new(paths: &[Vec<Point2<u16>>], part: Part) -> AocResult<Self> {
+        let (rocks, source) = build_cave(paths, part == Part::Two)?;
+        let grains: Vec<Position> = settle_sand(rocks.clone(), source).collect::<AocResult<_>>()?;
+        // The last row is the floor, or below all the rock if there isn't one.
+        let rock_cols =
+            (0..rocks.cols()).filter(|&col| (0..rocks.rows() - 1).any(|row| rocks[(row, col)]));
+        let used_cols = rock_cols
+            .chain(grains.iter().map(|&(_, col)| col))
+            .chain([source.1]);
+        let (min_col, max_col) = used_cols.fold((source.1, source.1), |(min, max), col| {
+            (min.min(col), max.max(col))
+        });
+        let cols = min_col.saturating_sub(1)..=(max_col + 1).min(rocks.cols() - 1);
+        Ok(Self {
+            rocks,
+            source,
+            grains,
+            cols,
+        })
+    }
+
+    /// The cave after the first `grain_count` grains.
+    fn draw(&self, grain_count: usize) -> Picture {
+        let first_col = *self.cols.start();
+        let mut pixels = Grid::new(self.rocks.rows(), self.cols.clone().count(), AIR);
+        for ((row, col), &rock) in self.rocks.enumerate() {
+            if rock && self.cols.contains(&col) {
+                pixels[(row, col - first_col)] = ROCK;
+            }
+        }
+        pixels[(self.source.0, self.source.1 - first_col)] = SOURCE;
+        for &(row, col) in &self.grains[..grain_count] {
+            pixels[(row, col - first_col)] = SAND;
+        }
+        Picture {
+            pixels,
+            palette: PALETTE.to_vec(),
+        }
+    }
+}
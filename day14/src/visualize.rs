@@ -0,0 +1,73 @@
+use std::iter;
+use std::ops::RangeInclusive;
+
+use common_utils::{AocResult, Frame, Grid, Part, Position, Visualize};
+
+use crate::{build_cave, settle_sand, Day14};
+
+impl Visualize for Day14 {
+    const FPS: u32 = 120;
+
+    /// The cave after every grain comes to rest, following the newest grain. The view widens as
+    /// the pile spreads past the rock.
+    fn frames(
+        paths: &Self::Parsed,
+        part: Part,
+    ) -> AocResult<impl Iterator<Item = AocResult<Frame>> + '_> {
+        let (rocks, source) = build_cave(paths, part == Part::Two)?;
+        let mut sand = Grid::new(rocks.rows(), rocks.cols(), false);
+        // The last row is the floor, or below all the rock if there isn't one.
+        let rock_cols =
+            (0..rocks.cols()).filter(|&col| (0..rocks.rows() - 1).any(|row| rocks[(row, col)]));
+        let mut min_col = rock_cols.clone().min().unwrap_or(source.1).min(source.1);
+        let mut max_col = rock_cols.max().unwrap_or(source.1).max(source.1);
+        let start = draw_cave(&rocks, &sand, source, min_col..=max_col, "start".to_owned());
+        let grains = settle_sand(rocks.clone(), source)
+            .enumerate()
+            .map(move |(i, position)| {
+                let position = position?;
+                sand[position] = true;
+                min_col = min_col.min(position.1);
+                max_col = max_col.max(position.1);
+                let caption = format!("grain {}", i + 1);
+                let frame = draw_cave(&rocks, &sand, source, min_col..=max_col, caption);
+                let (row, col) = position;
+                Ok(frame.with_focus((row, col - first_col(min_col))))
+            });
+        Ok(iter::once(Ok(start)).chain(grains))
+    }
+}
+
+/// Leaves a column of air left of everything.
+fn first_col(min_col: usize) -> usize {
+    min_col.saturating_sub(1)
+}
+
+/// `#` for rock, `o` for sand and `+` for the source, across `cols` and a column either side.
+fn draw_cave(
+    rocks: &Grid<bool>,
+    sand: &Grid<bool>,
+    source: Position,
+    cols: RangeInclusive<usize>,
+    caption: String,
+) -> Frame {
+    let cols = first_col(*cols.start())..=(*cols.end() + 1).min(rocks.cols() - 1);
+    let rows = (0..rocks.rows())
+        .map(|row| {
+            cols.clone()
+                .map(|col| {
+                    if rocks[(row, col)] {
+                        '#'
+                    } else if sand[(row, col)] {
+                        'o'
+                    } else if (row, col) == source {
+                        '+'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    Frame::new(caption, rows)
+}
@@ -0,0 +1,55 @@
+use common_utils::{Generator, Point2};
+use rand::Rng;
+
+use crate::Day15;
+
+/// The far corner of the square part 2 searches in a real input.
+const MAX_XY: i32 = 4_000_000;
+
+impl Generator for Day15 {
+    const SIZE_UNIT: &'static str = "sensors";
+    const DEFAULT_SIZE: usize = 30;
+
+    /// Picks the distress beacon's spot first, then covers everything else in the search square
+    /// with four big sensors whose ranges stop just short of it. The rest of the sensors are
+    /// scattered about and never reach it either. Needs at least four sensors.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let distress = Point2::new(rng.gen_range(0..=MAX_XY), rng.gen_range(0..=MAX_XY));
+        // Sensor ranges are squares in the rotated coordinates u = x + y, v = x - y. Those
+        // centered a radius-plus-one away along u or v leave only the distress beacon between
+        // them. The radius covers the whole search square, and is odd so the centers map back
+        // to whole x and y.
+        let (u, v) = (distress.x + distress.y, distress.x - distress.y);
+        let radius = (MAX_XY + v.abs() + 2) | 1;
+        let mut sensors: Vec<(Point2<i32>, i32)> = [
+            (u - radius - 1, v),
+            (u + radius + 1, v),
+            (u, v - radius - 1),
+            (u, v + radius + 1),
+        ]
+        .into_iter()
+        .map(|(u, v)| (Point2::new((u + v) / 2, (u - v) / 2), radius))
+        .collect();
+
+        while sensors.len() < size {
+            let sensor = Point2::new(rng.gen_range(0..=MAX_XY), rng.gen_range(0..=MAX_XY));
+            let distance = sensor.manhattan(distress);
+            if distance >= 2 {
+                sensors.push((sensor, rng.gen_range(distance / 2..distance)));
+            }
+        }
+
+        sensors
+            .into_iter()
+            .map(|(sensor, radius)| {
+                let dx = rng.gen_range(-radius..=radius);
+                let dy = (radius - dx.abs()) * if rng.gen_bool(0.5) { 1 } else { -1 };
+                let beacon = sensor + Point2::new(dx, dy);
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                    sensor.x, sensor.y, beacon.x, beacon.y
+                )
+            })
+            .collect()
+    }
+}
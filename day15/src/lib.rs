@@ -0,0 +1,287 @@
+use common_utils::{
+    parse_lines, Answer, AocError, AocResult, Coord, InputKind, Interval, Point2, RangeSet,
+    Solution,
+};
+use itertools::Itertools;
+
+#[cfg(feature = "generator")]
+pub mod generator;
+#[cfg(feature = "render")]
+pub mod render;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Parsed = Report;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        Self::parse_as(input, InputKind::Real)
+    }
+
+    fn parse_as(input: &str, kind: InputKind) -> AocResult<Self::Parsed> {
+        Ok(Report {
+            sensors: parse_lines(input, parser::sensor).try_collect()?,
+            search: Search::for_kind(kind),
+        })
+    }
+
+    fn part1(report: &Self::Parsed) -> AocResult<Answer> {
+        Ok(part1(&report.sensors, report.search.row)?.into())
+    }
+
+    fn part2(report: &Self::Parsed) -> AocResult<Answer> {
+        Ok(part2(&report.sensors, report.search.max_xy)?.into())
+    }
+}
+
+/// The sensors, and where the puzzle asks to look for the distress beacon.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    pub sensors: Vec<Sensor>,
+    pub search: Search,
+}
+
+/// The row to check in part 1 and the far corner of the square to search in part 2.
+///
+/// These come from the puzzle text rather than the input, and the example uses smaller ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Search {
+    pub row: i64,
+    pub max_xy: i64,
+}
+
+impl Search {
+    pub const SAMPLE: Search = Search {
+        row: 10,
+        max_xy: 20,
+    };
+    pub const REAL: Search = Search {
+        row: 2_000_000,
+        max_xy: 4_000_000,
+    };
+
+    pub fn for_kind(kind: InputKind) -> Self {
+        match kind {
+            InputKind::Real => Search::REAL,
+            InputKind::Sample => Search::SAMPLE,
+        }
+    }
+}
+
+/// Counts the positions on row `y` that some sensor rules out, apart from known beacons.
+pub fn part1(sensors: &[Sensor], y: i64) -> AocResult<u64> {
+    let covered: RangeSet<i64> = sensors
+        .iter()
+        .filter_map(|sensor| sensor.row_coverage(y).transpose())
+        .collect::<AocResult<_>>()?;
+    let beacons: RangeSet<i64> = sensors
+        .iter()
+        .filter(|sensor| i64::from(sensor.nearest_beacon.y) == y)
+        .map(|sensor| Interval::point(sensor.nearest_beacon.x.into()))
+        .collect();
+    let ruled_out = covered.difference(&beacons).len();
+    u64::try_from(ruled_out).map_err(|_| {
+        AocError::invalid_state(format!("{} positions ruled out on one row", ruled_out))
+    })
+}
+
+pub fn part2(sensors: &[Sensor], max_xy: i64) -> AocResult<u64> {
+    let distress_location = distress_beacon(sensors, max_xy)?;
+    tuning_frequency(distress_location)
+}
+
+/// `4,000,000 * x + y`.
+fn tuning_frequency(point: Point2<i64>) -> AocResult<u64> {
+    let x = u64::try_from(point.x).ok();
+    let y = u64::try_from(point.y).ok();
+    x.zip(y)
+        .and_then(|(x, y)| x.checked_mul(4_000_000)?.checked_add(y))
+        .ok_or_else(|| AocError::invalid_state(format!("No tuning frequency for {:?}", point)))
+}
+
+/// The one point in `0..=max_xy` squared that no sensor covers. It must be just outside some
+/// sensor's radius, so only those edges are searched.
+pub fn distress_beacon(sensors: &[Sensor], max_xy: i64) -> AocResult<Point2<i64>> {
+    let boundaries: Vec<_> = sensors.iter().map(Sensor::boundary).try_collect()?;
+    boundaries
+        .into_iter()
+        .flatten()
+        .filter(|&point| point.x >= 0 && point.x <= max_xy && point.y >= 0 && point.y <= max_xy)
+        .filter(|&point| sensors.iter().all(|sensor| !sensor.covers(point)))
+        .dedup()
+        .inspect(|point| tracing::debug!(?point, "uncovered point"))
+        .exactly_one()
+        .map_err(|_| AocError::no_solution("Expected exactly one uncovered point"))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Sensor {
+    pub sensor_location: Point2<i32>,
+    pub nearest_beacon: Point2<i32>,
+    /// Wider than the locations, since two of them can be up to `2^33` apart.
+    pub l_1_radius: i64,
+}
+
+impl Sensor {
+    pub fn new(sensor_location: Point2<i32>, nearest_beacon: Point2<i32>) -> Self {
+        Self {
+            sensor_location,
+            nearest_beacon,
+            l_1_radius: to_i64(sensor_location).manhattan(to_i64(nearest_beacon)),
+        }
+    }
+
+    /// The sensor's location, in the radius's type.
+    pub fn center(&self) -> Point2<i64> {
+        to_i64(self.sensor_location)
+    }
+
+    /// Whether `point` is within this sensor's radius.
+    pub fn covers(&self, point: Point2<i64>) -> bool {
+        let center = self.center();
+        // Each distance fits in a u64, so their sum can't overflow a u128.
+        let distance = center.x.wide_distance(point.x) + center.y.wide_distance(point.y);
+        u128::try_from(self.l_1_radius).is_ok_and(|radius| distance <= radius)
+    }
+
+    /// The x positions on row `y` within this sensor's radius.
+    pub fn row_coverage(&self, y: i64) -> AocResult<Option<Interval<i64>>> {
+        let center = self.center();
+        // A row too far away for an i64 to count is out of any sensor's range.
+        let Ok(dy) = i64::try_from(center.y.abs_diff(y)) else {
+            return Ok(None);
+        };
+        let Some(half_width) = self.l_1_radius.checked_sub(dy).filter(|&w| w >= 0) else {
+            return Ok(None);
+        };
+        match (
+            center.x.checked_sub(half_width),
+            center.x.checked_add(half_width),
+        ) {
+            (Some(start), Some(end)) => Ok(Interval::new(start, end)),
+            _ => Err(self.out_of_range()),
+        }
+    }
+
+    /// Every point just out of this sensor's range.
+    fn boundary(&self) -> AocResult<impl Iterator<Item = Point2<i64>>> {
+        let center = self.center();
+        let fits = |edge: i64| {
+            [center.x, center.y]
+                .into_iter()
+                .all(|c| c.checked_sub(edge).is_some() && c.checked_add(edge).is_some())
+        };
+        let edge = self
+            .l_1_radius
+            .checked_add(1)
+            .filter(|&edge| fits(edge))
+            .ok_or_else(|| self.out_of_range())?;
+        Ok(center.manhattan_circle(edge))
+    }
+
+    fn out_of_range(&self) -> AocError {
+        AocError::invalid_state(format!(
+            "Sensor at {:?} with radius {} reaches past the i64 range",
+            self.sensor_location, self.l_1_radius
+        ))
+    }
+}
+
+/// Widens a parsed location, so sums and differences of locations and radii can't overflow.
+pub(crate) fn to_i64(point: Point2<i32>) -> Point2<i64> {
+    Point2::new(point.x.into(), point.y.into())
+}
+
+pub mod parser {
+    use common_utils::ParseResult;
+    use nom::{
+        bytes::complete::tag,
+        character::complete::i32 as nom_i32,
+        combinator::map,
+        error::context,
+        sequence::{preceded, separated_pair},
+    };
+
+    use super::*;
+
+    pub fn sensor(s: &str) -> ParseResult<'_, Sensor> {
+        map(
+            separated_pair(sensor_location, tag(": "), beacon_location),
+            |(sensor_loc, beacon_loc)| Sensor::new(sensor_loc, beacon_loc),
+        )(s)
+    }
+
+    pub fn sensor_location(s: &str) -> ParseResult<'_, Point2<i32>> {
+        context("sensor location", preceded(tag("Sensor at "), point))(s)
+    }
+
+    pub fn beacon_location(s: &str) -> ParseResult<'_, Point2<i32>> {
+        context(
+            "beacon location",
+            preceded(tag("closest beacon is at "), point),
+        )(s)
+    }
+
+    pub fn point(s: &str) -> ParseResult<'_, Point2<i32>> {
+        map(
+            separated_pair(
+                preceded(tag("x="), context("x coordinate", nom_i32)),
+                tag(", "),
+                preceded(tag("y="), context("y coordinate", nom_i32)),
+            ),
+            |(x, y)| Point2::new(x, y),
+        )(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample() -> AocResult<()> {
+        const SAMPLE_INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+        let report = Day15::parse_as(SAMPLE_INPUT, InputKind::Sample)?;
+
+        assert_eq!(report.search, Search::SAMPLE);
+        assert_eq!(Day15::part1(&report)?, Answer::Int(26));
+        assert_eq!(Day15::part2(&report)?, Answer::Int(56000011));
+        Ok(())
+    }
+
+    #[test]
+    fn extreme_coordinates_dont_overflow() -> AocResult<()> {
+        let sensor = Sensor::new(
+            Point2::new(i32::MIN, i32::MIN),
+            Point2::new(i32::MAX, i32::MAX),
+        );
+        let radius = 2 * i64::from(u32::MAX);
+        assert_eq!(sensor.l_1_radius, radius);
+        let half_width = radius + i64::from(i32::MIN);
+        assert_eq!(part1(&[sensor], 0)?, 2 * half_width as u64 + 1);
+
+        let huge = Sensor {
+            sensor_location: Point2::new(i32::MAX, 0),
+            nearest_beacon: Point2::new(i32::MAX, 0),
+            l_1_radius: i64::MAX,
+        };
+        assert!(part1(&[huge], 0).is_err());
+        assert!(part2(&[huge], 20).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use common_utils::{
+    AocError, AocResult, BoundingBox, Grid, Part, Picture, Point2, Position, Render, Rgb,
+};
+
+use crate::{distress_beacon, to_i64, Day15, Search, Sensor};
+
+const BACKGROUND: u8 = 0;
+/// Sensors' diamonds take turns through these, starting at palette index 1.
+const HUES: [Rgb; 12] = [
+    [70, 110, 160],
+    [150, 90, 140],
+    [90, 140, 90],
+    [170, 120, 70],
+    [80, 140, 150],
+    [140, 80, 80],
+    [110, 100, 170],
+    [150, 150, 80],
+    [70, 120, 110],
+    [160, 100, 110],
+    [100, 130, 70],
+    [130, 110, 140],
+];
+const SENSOR: u8 = 13;
+const BEACON: u8 = 14;
+const HIGHLIGHT: u8 = 15;
+const SEARCH_SQUARE: u8 = 16;
+
+/// The longer side of the picture, in pixels. Real inputs span millions of positions, so each
+/// pixel stands for a square of them.
+const MAX_SIDE: i64 = 512;
+
+impl Render for Day15 {
+    /// Every sensor's diamond in its own colour, with sensors in white and their beacons in red.
+    /// Part 1 draws the row it counts along in green wherever it's covered. Part 2 outlines the
+    /// search square in grey and marks the distress beacon in green.
+    fn picture(report: &Self::Parsed, part: Part) -> AocResult<Picture> {
+        let sensors = &report.sensors;
+        let Search { row, max_xy } = report.search;
+        let view = View::around(sensors)?;
+        let mut picture = view.diamonds(sensors);
+        let marker_size = if view.units_per_pixel > 1 { 2 } else { 0 };
+        match part {
+            Part::One => {
+                if let Some((pixel_row, _)) = view.pixel(Point2::new(view.min.x, row)) {
+                    for pixel in picture.pixels.row_mut(pixel_row) {
+                        if *pixel != BACKGROUND {
+                            *pixel = HIGHLIGHT;
+                        }
+                    }
+                }
+            }
+            Part::Two => {
+                let corners = [Point2::new(0, 0), Point2::new(max_xy, max_xy)];
+                if let [Some(top_left), Some(bottom_right)] = corners.map(|p| view.pixel(p)) {
+                    for row in top_left.0..=bottom_right.0 {
+                        for col in top_left.1..=bottom_right.1 {
+                            let edge = row == top_left.0
+                                || row == bottom_right.0
+                                || col == top_left.1
+                                || col == bottom_right.1;
+                            if edge {
+                                picture.pixels[(row, col)] = SEARCH_SQUARE;
+                            }
+                        }
+                    }
+                }
+                let beacon = distress_beacon(sensors, max_xy)?;
+                view.mark(&mut picture, beacon, HIGHLIGHT, marker_size + 1);
+            }
+        }
+        for sensor in sensors {
+            view.mark(
+                &mut picture,
+                to_i64(sensor.nearest_beacon),
+                BEACON,
+                marker_size,
+            );
+            view.mark(
+                &mut picture,
+                to_i64(sensor.sensor_location),
+                SENSOR,
+                marker_size,
+            );
+        }
+        Ok(picture)
+    }
+}
+
+/// Which puzzle positions the picture's pixels stand for.
+struct View {
+    /// The position at the top-left corner.
+    min: Point2<i64>,
+    /// How many positions each pixel is wide and high.
+    units_per_pixel: i64,
+    rows: usize,
+    cols: usize,
+}
+
+impl View {
+    /// Big enough for every sensor's diamond.
+    fn around(sensors: &[Sensor]) -> AocResult<Self> {
+        let reach = |sensor: &Sensor| {
+            let centre = sensor.center();
+            let radius = sensor.l_1_radius;
+            [
+                centre - Point2::new(radius, radius),
+                centre + Point2::new(radius, radius),
+            ]
+        };
+        let bounds = BoundingBox::from_points(sensors.iter().flat_map(reach))
+            .ok_or_else(|| AocError::invalid_state("No sensors to draw"))?;
+        let longest = bounds.width().max(bounds.height());
+        let units_per_pixel = (longest + MAX_SIDE - 1) / MAX_SIDE;
+        let pixels = |length: i64| ((length + units_per_pixel - 1) / units_per_pixel) as usize;
+        Ok(Self {
+            min: bounds.min,
+            units_per_pixel,
+            rows: pixels(bounds.height()),
+            cols: pixels(bounds.width()),
+        })
+    }
+
+    /// The position in the middle of `pixel`.
+    fn point(&self, (row, col): Position) -> Point2<i64> {
+        let offset =
+            |pixels: usize| pixels as i64 * self.units_per_pixel + self.units_per_pixel / 2;
+        self.min + Point2::new(offset(col), offset(row))
+    }
+
+    fn pixel(&self, point: Point2<i64>) -> Option<Position> {
+        let offset = point - self.min;
+        let row = usize::try_from(offset.y / self.units_per_pixel).ok()?;
+        let col = usize::try_from(offset.x / self.units_per_pixel).ok()?;
+        (row < self.rows && col < self.cols).then_some((row, col))
+    }
+
+    /// Colours each pixel by the sensor it's deepest inside, relative to that sensor's radius.
+    fn diamonds(&self, sensors: &[Sensor]) -> Picture {
+        let mut palette = vec![[10, 10, 20]];
+        palette.extend(HUES);
+        palette.extend([
+            [255, 255, 255],
+            [230, 40, 40],
+            [60, 230, 60],
+            [160, 160, 160],
+        ]);
+        Picture::from_grid(&Grid::new(self.rows, self.cols, ()), palette, |pixel, _| {
+            let point = self.point(pixel);
+            let depth = |sensor: &Sensor| {
+                let distance = sensor.center().manhattan(point);
+                (distance, sensor.l_1_radius.max(1))
+            };
+            sensors
+                .iter()
+                .enumerate()
+                .map(|(i, sensor)| (i, depth(sensor)))
+                .filter(|&(_, (distance, radius))| distance <= radius)
+                // Compares distance / radius without dividing.
+                .min_by(|&(_, (d1, r1)), &(_, (d2, r2))| (d1 * r2).cmp(&(d2 * r1)))
+                .map_or(BACKGROUND, |(i, _)| 1 + (i % HUES.len()) as u8)
+        })
+    }
+
+    /// Draws a square `size` pixels out from `point` either way, clipped to the picture.
+    fn mark(&self, picture: &mut Picture, point: Point2<i64>, colour: u8, size: usize) {
+        let Some((row, col)) = self.pixel(point) else {
+            return;
+        };
+        let rows = row.saturating_sub(size)..=(row + size).min(self.rows - 1);
+        for row in rows {
+            for col in col.saturating_sub(size)..=(col + size).min(self.cols - 1) {
+                picture.pixels[(row, col)] = colour;
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Checks the search along the sensors' range boundaries against trying every point in a small
+//! square.
+
+use common_utils::Point2;
+use day15::{part2, Sensor};
+use proptest::prelude::*;
+
+const MAX_XY: i32 = 20;
+
+/// Every point in the square that no sensor rules out.
+fn naive_uncovered(sensors: &[Sensor]) -> Vec<Point2<i64>> {
+    let max_xy = i64::from(MAX_XY);
+    (0..=max_xy)
+        .flat_map(|x| (0..=max_xy).map(move |y| Point2::new(x, y)))
+        .filter(|&point| sensors.iter().all(|sensor| !sensor.covers(point)))
+        .collect()
+}
+
+/// Sensors around a chosen gap, each reaching as far towards it as it can (or one short).
+///
+/// Half the time four more sensors cover the rest of the square, stopping just short of the
+/// gap, so it's the only point left uncovered; those come with the gap.
+fn sensors_around_gap() -> impl Strategy<Value = (Vec<Sensor>, Option<Point2<i64>>)> {
+    let gap = (0..=MAX_XY, 0..=MAX_XY).prop_map(|(x, y)| Point2::new(x, y));
+    let sensor = (-5..=MAX_XY + 5, -5..=MAX_XY + 5, 0..=1, any::<bool>());
+    (gap, prop::collection::vec(sensor, 0..8), any::<bool>()).prop_map(|(gap, sensors, enclose)| {
+        let mut placed: Vec<(Point2<i32>, i32, bool)> = sensors
+            .into_iter()
+            .map(|(x, y, slack, vertical)| (Point2::new(x, y), slack, vertical))
+            .collect();
+        if enclose {
+            // In u = x + y, v = x - y a sensor's range is a square; these four border the
+            // gap on each side. An odd radius keeps their centers on whole x and y.
+            let (u, v) = (gap.x + gap.y, gap.x - gap.y);
+            let radius = (MAX_XY + v.abs() + 2) | 1;
+            placed.extend(
+                [
+                    (u - radius - 1, v),
+                    (u + radius + 1, v),
+                    (u, v - radius - 1),
+                    (u, v + radius + 1),
+                ]
+                .map(|(u, v)| (Point2::new((u + v) / 2, (u - v) / 2), 0, false)),
+            );
+        }
+        let sensors = placed
+            .into_iter()
+            .filter(|&(location, slack, _)| location.manhattan(gap) > slack)
+            .map(|(location, slack, vertical)| {
+                let radius = location.manhattan(gap) - 1 - slack;
+                let offset = if vertical {
+                    Point2::new(0, radius)
+                } else {
+                    Point2::new(radius, 0)
+                };
+                Sensor::new(location, location + offset)
+            })
+            .collect();
+        let gap = Point2::new(gap.x.into(), gap.y.into());
+        (sensors, enclose.then_some(gap))
+    })
+}
+
+fn tuning_frequency(point: Point2<i64>) -> u64 {
+    4000000 * point.x as u64 + point.y as u64
+}
+
+proptest! {
+    #[test]
+    fn boundary_search_matches_naive((sensors, enclosed_gap) in sensors_around_gap()) {
+        let uncovered = naive_uncovered(&sensors);
+        if let Some(gap) = enclosed_gap {
+            prop_assert_eq!(&uncovered, &[gap]);
+            prop_assert_eq!(part2(&sensors, MAX_XY.into()).ok(), Some(tuning_frequency(gap)));
+        }
+        match part2(&sensors, MAX_XY.into()) {
+            Ok(frequency) => prop_assert!(
+                uncovered.iter().any(|&point| tuning_frequency(point) == frequency),
+                "{} isn't the frequency of an uncovered point",
+                frequency
+            ),
+            Err(e) => prop_assert!(uncovered.len() != 1, "missed {:?}: {}", uncovered, e),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use common_utils::Generator;
+use rand::Rng;
+
+use crate::Day2;
+
+impl Generator for Day2 {
+    const SIZE_UNIT: &'static str = "rounds";
+    const DEFAULT_SIZE: usize = 2500;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let opponent = rng.gen_range(b'A'..=b'C') as char;
+                let response = rng.gen_range(b'X'..=b'Z') as char;
+                format!("{} {}\n", opponent, response)
+            })
+            .collect()
+    }
+}
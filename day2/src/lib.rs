@@ -0,0 +1,133 @@
+use common_utils::{Answer, AocResult, ParseDiagnostic, Solution};
+
+#[cfg(feature = "generator")]
+pub mod generator;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    /// The opponent's throw in each round, then the second column read both as my throw (for
+    /// part 1) and as the outcome I need (for part 2).
+    type Parsed = Vec<(RPS, RPS, Outcome)>;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let error = |column, expected| ParseDiagnostic::new(i + 1, column, line, expected);
+                let round = match *line.as_bytes() {
+                    [other @ b'A'..=b'C', b' ', second] => (
+                        RPS::from_utf8_byte(other),
+                        RPS::from_utf8_byte(second),
+                        Outcome::from_utf8_byte(second),
+                    ),
+                    [_, b' ', _] => return Err(error(1, "A, B or C").into()),
+                    _ => return Err(error(1, "a round like 'A X'").into()),
+                };
+                match round {
+                    (Some(other), Some(mine), Some(outcome)) => Ok((other, mine, outcome)),
+                    _ => Err(error(3, "X, Y or Z").into()),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> AocResult<Answer> {
+        let total = parsed
+            .iter()
+            .map(|&(other, mine, _)| RPS::round_value(mine, other))
+            .sum::<u64>();
+        Ok(total.into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> AocResult<Answer> {
+        let total = parsed
+            .iter()
+            .map(|&(other_throw, _, outcome)| outcome.score(other_throw))
+            .sum::<u64>();
+        Ok(total.into())
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy)]
+pub enum RPS {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Clone, Copy)]
+pub enum Outcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+impl Outcome {
+    pub fn from_utf8_byte(byte: u8) -> Option<Self> {
+        match byte {
+            b'X' => Some(Outcome::Loss),
+            b'Y' => Some(Outcome::Draw),
+            b'Z' => Some(Outcome::Win),
+            _ => None,
+        }
+    }
+
+    pub fn score(self, other_throw: RPS) -> u64 {
+        match self {
+            Outcome::Loss => other_throw.losing_throw().score(),
+            Outcome::Draw => 3 + other_throw.score(),
+            Outcome::Win => 6 + other_throw.beating_throw().score(),
+        }
+    }
+}
+
+impl RPS {
+    pub fn from_utf8_byte(byte: u8) -> Option<Self> {
+        match byte {
+            b'A' | b'X' => Some(RPS::Rock),
+            b'B' | b'Y' => Some(RPS::Paper),
+            b'C' | b'Z' => Some(RPS::Scissors),
+            _ => None,
+        }
+    }
+
+    pub fn score(self) -> u64 {
+        match self {
+            RPS::Rock => 1,
+            RPS::Paper => 2,
+            RPS::Scissors => 3,
+        }
+    }
+
+    pub fn round_value(mine: RPS, other: RPS) -> u64 {
+        use RPS::*;
+
+        mine.score()
+            + match (mine, other) {
+                (Rock, Paper) | (Paper, Scissors) | (Scissors, Rock) => 0,
+                (Paper, Rock) | (Scissors, Paper) | (Rock, Scissors) => 6,
+                _ => 3,
+            }
+    }
+
+    pub fn beating_throw(self) -> Self {
+        match self {
+            RPS::Rock => RPS::Paper,
+            RPS::Scissors => RPS::Rock,
+            RPS::Paper => RPS::Scissors,
+        }
+    }
+
+    pub fn losing_throw(self) -> Self {
+        match self {
+            RPS::Rock => RPS::Scissors,
+            RPS::Scissors => RPS::Paper,
+            RPS::Paper => RPS::Rock,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use common_utils::Generator;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Day3;
+
+const ITEM_TYPES: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl Generator for Day3 {
+    const SIZE_UNIT: &'static str = "rucksacks";
+    const DEFAULT_SIZE: usize = 300;
+
+    /// Rounds `size` up to whole groups of three.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let groups = size.max(1).div_ceil(3);
+        (0..groups).map(|_| group(rng)).collect()
+    }
+}
+
+/// Three rucksacks sharing exactly one badge, each with exactly one type in both compartments.
+///
+/// Apart from the badge, every rucksack draws from its own 17 item types: 8 for the left
+/// compartment only, 8 for the right only, and one that may be the type in both.
+fn group<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let mut types = ITEM_TYPES.to_vec();
+    types.shuffle(rng);
+    let (badge, rest) = (types[0], &types[1..]);
+    rest.chunks(17)
+        .map(|own| {
+            let (left_only, right_only, spare) = (&own[..8], &own[8..16], own[16]);
+            let shared = if rng.gen_bool(0.5) { badge } else { spare };
+            let mut left = vec![shared];
+            if shared != badge {
+                left.push(badge);
+            }
+            let half = rng.gen_range(left.len()..=16);
+            let mut right = vec![shared];
+            left.extend((left.len()..half).map(|_| left_only[rng.gen_range(0..8)]));
+            right.extend((1..half).map(|_| right_only[rng.gen_range(0..8)]));
+            left.shuffle(rng);
+            right.shuffle(rng);
+            left.into_iter()
+                .chain(right)
+                .map(char::from)
+                .chain(Some('\n'))
+                .collect::<String>()
+        })
+        .collect()
+}
@@ -0,0 +1,117 @@
+use common_utils::{Answer, AocError, AocResult, ParseDiagnostic, Solution};
+use itertools::Itertools;
+
+#[cfg(feature = "generator")]
+pub mod generator;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    /// Each rucksack's items, all ASCII letters and an even number of them.
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                if let Some(column) = line.bytes().position(|b| !b.is_ascii_alphabetic()) {
+                    return Err(
+                        ParseDiagnostic::new(i + 1, column + 1, line, "an item letter").into(),
+                    );
+                }
+                if line.len() % 2 == 1 {
+                    let expected = "an even number of items";
+                    return Err(ParseDiagnostic::new(i + 1, line.len() + 1, line, expected).into());
+                }
+                Ok(line.to_owned())
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> AocResult<Answer> {
+        let priority_sum = parsed
+            .iter()
+            .map(|line| find_shared_priority(line))
+            .sum::<AocResult<u64>>()?;
+        Ok(priority_sum.into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> AocResult<Answer> {
+        let group_sum = parsed
+            .iter()
+            .map(String::as_str)
+            .chunks(3)
+            .into_iter()
+            .map(find_group_priority)
+            .sum::<AocResult<u64>>()?;
+        Ok(group_sum.into())
+    }
+}
+
+pub fn find_group_priority<'a>(groups: impl Iterator<Item = &'a str>) -> AocResult<u64> {
+    let mut counts = [0u8; 52];
+    let mut members = 0;
+    for line in groups {
+        members += 1;
+        let mut appears = [0u8; 52];
+        for &byte in line.as_bytes() {
+            let priority = utf8_byte_to_priority(byte).ok_or_else(|| {
+                AocError::invalid_state(format!("{:?} isn't an item", byte as char))
+            })?;
+            appears[priority as usize - 1] = 1;
+        }
+        counts
+            .iter_mut()
+            .zip(appears)
+            .for_each(|(count, appears_val)| {
+                *count += appears_val;
+            });
+    }
+    if members == 0 {
+        return Err(AocError::invalid_state("Empty group of elves"));
+    }
+    counts
+        .into_iter()
+        .find_position(|&count| count == members)
+        .map(|(i, _)| i as u64 + 1)
+        .ok_or_else(|| AocError::no_solution("No item is common to the whole group"))
+}
+
+pub fn find_shared_priority(contents: &str) -> AocResult<u64> {
+    let mut has_seen = [false; 52];
+    let bytes = contents.as_bytes();
+    let (first_compartment, second_compartment) = bytes.split_at(bytes.len() / 2);
+    let priority = |byte: u8| {
+        utf8_byte_to_priority(byte)
+            .ok_or_else(|| AocError::invalid_state(format!("{:?} isn't an item", byte as char)))
+    };
+    for &byte in first_compartment {
+        let priority = priority(byte)?;
+        unsafe {
+            *has_seen.get_unchecked_mut((priority as usize) - 1) = true;
+        }
+    }
+    for &byte in second_compartment {
+        let priority = priority(byte)?;
+        if unsafe { *has_seen.get_unchecked((priority as usize) - 1) } {
+            return Ok(priority);
+        }
+    }
+    Err(AocError::no_solution(format!(
+        "No item is in both compartments of {}",
+        contents
+    )))
+}
+
+/// Lowercase items have priority 1 through 26, uppercase 27 through 52.
+pub fn utf8_byte_to_priority(byte: u8) -> Option<u64> {
+    let offset = match byte {
+        b'a'..=b'z' => byte - b'a',
+        b'A'..=b'Z' => byte - b'A' + 26,
+        _ => return None,
+    };
+    Some(1 + offset as u64)
+}
@@ -0,0 +1,19 @@
+use common_utils::Generator;
+use rand::Rng;
+
+use crate::Day4;
+
+impl Generator for Day4 {
+    const SIZE_UNIT: &'static str = "pairs";
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let mut range = || {
+            let start = rng.gen_range(1..=99);
+            format!("{}-{}", start, rng.gen_range(start..=99))
+        };
+        (0..size.max(1))
+            .map(|_| format!("{},{}\n", range(), range()))
+            .collect()
+    }
+}
@@ -0,0 +1,53 @@
+use common_utils::{Answer, AocResult, Interval, ParseDiagnostic, Solution};
+use itertools::Itertools;
+
+#[cfg(feature = "generator")]
+pub mod generator;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<(Interval<u32>, Interval<u32>)>;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let error = |column, expected| ParseDiagnostic::new(i + 1, column, line, expected);
+                let (a, b) = line
+                    .split_once(',')
+                    .ok_or_else(|| error(line.len() + 1, "','"))?;
+                let a = parse_range(a).map_err(|expected| error(1, expected))?;
+                let b =
+                    parse_range(b).map_err(|expected| error(line.len() - b.len() + 1, expected))?;
+                Ok((a, b))
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> AocResult<Answer> {
+        let contains_count = parsed
+            .iter()
+            .filter(|(a, b)| a.covers(*b) || b.covers(*a))
+            .count();
+        Ok(contains_count.into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> AocResult<Answer> {
+        let overlapping_count = parsed.iter().filter(|(a, b)| a.overlaps(*b)).count();
+        Ok(overlapping_count.into())
+    }
+}
+
+/// Parses a section assignment like `2-4`, or says what was expected instead.
+pub fn parse_range(s: &str) -> Result<Interval<u32>, &'static str> {
+    let (start, end) = s
+        .split('-')
+        .collect_tuple()
+        .and_then(|(start, end)| Some((start.parse().ok()?, end.parse().ok()?)))
+        .ok_or("a range like 2-4")?;
+    Interval::new(start, end).ok_or("a range that doesn't end before it starts")
+}
@@ -1,79 +1,7 @@
-use std::io::BufRead;
-use std::str::FromStr;
-
-use ranges::Range;
-
-use anyhow::{anyhow, Result};
-use common_utils::get_buffered_input;
-use itertools::Itertools;
+use color_eyre::eyre::Result;
+use day4::Day4;
 
 fn main() -> Result<()> {
-    let contains_count = get_buffered_input()
-        .lines()
-        .map(|line_res| {
-            let line = line_res?;
-            let mut split = line.split(',');
-            match (split.next(), split.next(), split.next()) {
-                (Some(a), Some(b), None) => Ok((Range::from_str(a)?, Range::from_str(b)?)),
-                _ => Err(anyhow!("Expected RANGE,RANGE got {}", line)),
-            }
-        })
-        .filter_ok(|(a, b)| a.contains(*b) || b.contains(*a))
-        .fold_ok(0usize, |acc, _| acc + 1)?;
-    println!("{} contained pairs", contains_count);
-
-    let overlapping_count = get_buffered_input()
-        .lines()
-        .map(|line_res| {
-            let line = line_res?;
-            let mut split = line.split(',');
-            match (split.next(), split.next(), split.next()) {
-                (Some(a), Some(b), None) => Ok((Range::from_str(a)?, Range::from_str(b)?)),
-                _ => Err(anyhow!("Expected RANGE,RANGE got {}", line)),
-            }
-        })
-        .filter_ok(|(a, b)| a.overlaps(*b))
-        .fold_ok(0usize, |acc, _| acc + 1)?;
-    println!("{} overlapping pairs", overlapping_count);
-    Ok(())
-}
-
-mod ranges {
-    use anyhow::{anyhow, Result};
-    use itertools::Itertools;
-    use std::str::FromStr;
-
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-    pub struct Range {
-        start: usize,
-        end: usize,
-    }
-
-    impl FromStr for Range {
-        type Err = anyhow::Error;
-
-        fn from_str(s: &str) -> Result<Self> {
-            let parts = s.split('-');
-            match parts.collect_tuple::<(&str, &str)>() {
-                Some((a, b)) => Ok(Range {
-                    start: a.parse()?,
-                    end: b.parse()?,
-                }),
-                None => Err(anyhow!("Expected A-B, got {}", s)),
-            }
-        }
-    }
-
-    impl Range {
-        pub fn contains(self, other: Self) -> bool {
-            self.start <= other.start && self.end >= other.end
-        }
-
-        pub fn overlaps(self, other: Self) -> bool {
-            let other_range = other.start..=other.end;
-            other_range.contains(&self.start)
-                || other_range.contains(&self.end)
-                || self.contains(other)
-        }
-    }
+    color_eyre::install()?;
+    common_utils::run::<Day4>()
 }
@@ -0,0 +1,53 @@
+use common_utils::Generator;
+use rand::Rng;
+
+use crate::Day5;
+
+impl Generator for Day5 {
+    const SIZE_UNIT: &'static str = "moves";
+    const DEFAULT_SIZE: usize = 500;
+
+    /// Every move takes crates that are there, so both cranes can carry out the whole
+    /// procedure, and leaves at least one behind, so every stack has a top at the end.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let stacks = rng.gen_range(2..=9);
+        let mut heights: Vec<usize> = (0..stacks).map(|_| rng.gen_range(1..=8)).collect();
+        // With more crates than stacks, some stack always has one to spare.
+        heights[0] = heights[0].max(2);
+        let tallest = heights.iter().copied().max().unwrap_or(0);
+
+        let mut drawing = String::new();
+        for level in (0..tallest).rev() {
+            let row: Vec<String> = heights
+                .iter()
+                .map(|&height| {
+                    if height > level {
+                        format!("[{}]", rng.gen_range(b'A'..=b'Z') as char)
+                    } else {
+                        "   ".to_owned()
+                    }
+                })
+                .collect();
+            drawing.push_str(&row.join(" "));
+            drawing.push('\n');
+        }
+        let labels: Vec<String> = (1..=stacks).map(|i| format!(" {} ", i)).collect();
+        drawing.push_str(&labels.join(" "));
+        drawing.push_str("\n\n");
+
+        for _ in 0..size.max(1) {
+            let from = loop {
+                let from = rng.gen_range(0..stacks);
+                if heights[from] > 1 {
+                    break from;
+                }
+            };
+            let to = (from + rng.gen_range(1..stacks)) % stacks;
+            let count = rng.gen_range(1..heights[from].min(11));
+            heights[from] -= count;
+            heights[to] += count;
+            drawing.push_str(&format!("move {} from {} to {}\n", count, from + 1, to + 1));
+        }
+        drawing
+    }
+}
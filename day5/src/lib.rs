@@ -0,0 +1,142 @@
+use std::str::FromStr;
+
+use common_utils::{sections, Answer, AocError, AocResult, ParseDiagnostic, Section, Solution};
+use itertools::Itertools;
+
+#[cfg(feature = "generator")]
+pub mod generator;
+#[cfg(feature = "visualize")]
+pub mod visualize;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Parsed = (Vec<Vec<u8>>, Vec<MoveSpec>);
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        let (drawing, moves) = sections(input).collect_tuple().ok_or_else(|| {
+            let end = input.lines().count() + 1;
+            ParseDiagnostic::new(end, 1, "", "a crate drawing and a list of moves")
+        })?;
+        let state = get_initial_state(drawing)?;
+        let moves = moves
+            .lines()
+            .zip(moves.first_line..)
+            .map(|(line, line_no)| {
+                MoveSpec::from_str(line)
+                    .map_err(|expected| ParseDiagnostic::new(line_no, 1, line, expected))
+            })
+            .try_collect()?;
+        Ok((state, moves))
+    }
+
+    fn part1(parsed: &Self::Parsed) -> AocResult<Answer> {
+        run_crane(parsed, Crane::CrateMover9000)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> AocResult<Answer> {
+        run_crane(parsed, Crane::CrateMover9001)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Crane {
+    /// Moves crates one at a time, reversing their order.
+    CrateMover9000,
+    /// Moves all the crates at once, keeping their order.
+    CrateMover9001,
+}
+
+pub fn run_crane(
+    (initial_state, moves): &(Vec<Vec<u8>>, Vec<MoveSpec>),
+    crane: Crane,
+) -> AocResult<Answer> {
+    let mut state = initial_state.clone();
+    moves
+        .iter()
+        .try_for_each(|move_spec| move_spec.do_move(&mut state, crane))?;
+    let tops: String = state
+        .into_iter()
+        .map(|v| v.last().map_or(' ', |&top| top as char))
+        .collect();
+    Ok(tops.into())
+}
+
+/// Reads the stacks out of the drawing, bottom crate first.
+pub fn get_initial_state(drawing: Section<'_>) -> AocResult<Vec<Vec<u8>>> {
+    let mut lines: Vec<(usize, &str)> = (drawing.first_line..).zip(drawing.lines()).collect();
+    let (_, last_line) = lines
+        .pop()
+        .ok_or_else(|| AocError::invalid_state("Empty crate drawing"))?;
+    let num_cols = last_line.split(' ').filter(|s| !s.is_empty()).count();
+    let mut stacks = vec![Vec::new(); num_cols];
+    for (line_no, line) in lines.into_iter().rev() {
+        for (i, chunk) in line.as_bytes().chunks(4).enumerate() {
+            let error = |expected| ParseDiagnostic::new(line_no, i * 4 + 1, line, expected);
+            match chunk.get(..3) {
+                Some(&[b' ', b' ', b' ']) => {}
+                Some(&[b'[', val, b']']) => stacks
+                    .get_mut(i)
+                    .ok_or_else(|| error("no crate beyond the last numbered stack"))?
+                    .push(val),
+                _ => return Err(error("a crate like [N], or a gap").into()),
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+pub struct MoveSpec {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl FromStr for MoveSpec {
+    /// What was expected instead.
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const EXPECTED: &str = "a move like 'move N from A to B'";
+        let (_mov, count, _from_text, from, _to_text, to): (&str, &str, &str, &str, &str, &str) =
+            s.split(' ').collect_tuple().ok_or(EXPECTED)?;
+        // skipping checking the text bits, we'll assume they're right
+        Ok(Self {
+            count: count.parse().map_err(|_| EXPECTED)?,
+            from: from.parse().map_err(|_| EXPECTED)?,
+            to: to.parse().map_err(|_| EXPECTED)?,
+        })
+    }
+}
+
+impl MoveSpec {
+    pub fn do_move(&self, stacks: &mut [Vec<u8>], crane: Crane) -> AocResult<()> {
+        let [src, dst] = self
+            .from
+            .checked_sub(1)
+            .zip(self.to.checked_sub(1))
+            .and_then(|(from, to)| stacks.get_disjoint_mut([from, to]).ok())
+            .ok_or_else(|| {
+                AocError::invalid_state(format!(
+                    "Can't move from stack {} to stack {}",
+                    self.from, self.to
+                ))
+            })?;
+        let remaining = src.len().checked_sub(self.count).ok_or_else(|| {
+            AocError::invalid_state(format!(
+                "Can't move {} crates off stack {}, it only has {}",
+                self.count,
+                self.from,
+                src.len()
+            ))
+        })?;
+        let tail = src.drain(remaining..);
+        match crane {
+            Crane::CrateMover9000 => dst.extend(tail.rev()),
+            Crane::CrateMover9001 => dst.extend(tail),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use std::iter;
+
+use common_utils::{AocResult, Frame, Part, Visualize};
+
+use crate::{Crane, Day5};
+
+impl Visualize for Day5 {
+    const FPS: u32 = 20;
+
+    /// The stacks before the first move and after every move, drawn like the puzzle's drawing.
+    fn frames(
+        (initial_state, moves): &Self::Parsed,
+        part: Part,
+    ) -> AocResult<impl Iterator<Item = AocResult<Frame>> + '_> {
+        let crane = match part {
+            Part::One => Crane::CrateMover9000,
+            Part::Two => Crane::CrateMover9001,
+        };
+        let mut stacks = initial_state.clone();
+        let start = Frame::new("starting stacks", draw_stacks(&stacks));
+        let steps = moves.iter().enumerate().map(move |(i, move_spec)| {
+            move_spec.do_move(&mut stacks, crane)?;
+            let caption = format!(
+                "move {} from {} to {} ({}/{})",
+                move_spec.count,
+                move_spec.from,
+                move_spec.to,
+                i + 1,
+                moves.len()
+            );
+            Ok(Frame::new(caption, draw_stacks(&stacks)))
+        });
+        Ok(iter::once(Ok(start)).chain(steps))
+    }
+}
+
+/// Draws the stacks as the puzzle does, top crate first, with the stack numbers underneath.
+pub fn draw_stacks(stacks: &[Vec<u8>]) -> Vec<String> {
+    let tallest = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut rows: Vec<String> = (0..tallest)
+        .rev()
+        .map(|level| {
+            let row: Vec<String> = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(&label) => format!("[{}]", label as char),
+                    None => "   ".to_owned(),
+                })
+                .collect();
+            row.join(" ")
+        })
+        .collect();
+    let numbers: Vec<String> = (1..=stacks.len())
+        .map(|number| format!("{:^3}", number))
+        .collect();
+    rows.push(numbers.join(" "));
+    rows
+}
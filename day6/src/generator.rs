@@ -0,0 +1,26 @@
+use common_utils::Generator;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Day6;
+
+impl Generator for Day6 {
+    const SIZE_UNIT: &'static str = "characters";
+    const DEFAULT_SIZE: usize = 4096;
+
+    /// Mostly noise from too few letters to hold a message marker, with one marker planted
+    /// somewhere in it.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let len = size.max(14);
+        let mut signal: Vec<u8> = (0..len).map(|_| rng.gen_range(b'a'..=b'm')).collect();
+        let mut marker: Vec<u8> = (b'a'..=b'z').collect();
+        marker.shuffle(rng);
+        let start = rng.gen_range(0..=len - 14);
+        signal[start..start + 14].copy_from_slice(&marker[..14]);
+        signal
+            .into_iter()
+            .map(char::from)
+            .chain(Some('\n'))
+            .collect()
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::VecDeque;
+use std::ops::ControlFlow;
+
+use common_utils::{Answer, AocError, AocResult, Solution};
+use itertools::Itertools;
+
+#[cfg(feature = "generator")]
+pub mod generator;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Parsed = Vec<u8>;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        Ok(input.trim_end().as_bytes().to_vec())
+    }
+
+    fn part1(parsed: &Self::Parsed) -> AocResult<Answer> {
+        Ok(get_start_of_packet(parsed.iter().copied())?.into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> AocResult<Answer> {
+        Ok(get_start_of_message(parsed.iter().copied())?.into())
+    }
+}
+
+pub fn get_start_of_packet(iter: impl IntoIterator<Item = u8>) -> AocResult<usize> {
+    find_first_run_of_n_distinct(iter, 4)
+}
+
+pub fn get_start_of_message(iter: impl IntoIterator<Item = u8>) -> AocResult<usize> {
+    find_first_run_of_n_distinct(iter, 14)
+}
+
+pub fn find_first_run_of_n_distinct(
+    iter: impl IntoIterator<Item = u8>,
+    n: usize,
+) -> AocResult<usize> {
+    let mut enumerated = iter.into_iter().enumerate().map(|(i, x)| (i + 1, x));
+    let mut last_n_seen: VecDeque<u8> = enumerated.by_ref().take(n).map(|(_, x)| x).collect();
+    if last_n_seen.len() == n && last_n_seen.iter().all_unique() {
+        return Ok(n);
+    }
+    match enumerated.try_for_each(|(i, val)| {
+        last_n_seen.pop_front();
+        last_n_seen.push_back(val);
+        if last_n_seen.iter().all_unique() {
+            ControlFlow::Break(i)
+        } else {
+            ControlFlow::Continue(())
+        }
+    }) {
+        ControlFlow::Continue(_) => Err(AocError::no_solution(format!(
+            "No run of {} distinct characters",
+            n
+        ))),
+        ControlFlow::Break(i) => Ok(i),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples() -> AocResult<()> {
+        let samples = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+        for (sample, packet, message) in samples {
+            assert_eq!(get_start_of_packet(sample.bytes())?, packet);
+            assert_eq!(get_start_of_message(sample.bytes())?, message);
+        }
+        Ok(())
+    }
+}
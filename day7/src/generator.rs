@@ -0,0 +1,88 @@
+use common_utils::Generator;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{Day7, NEEDED_SPACE, TOTAL_SPACE};
+
+impl Generator for Day7 {
+    const SIZE_UNIT: &'static str = "directories";
+    const DEFAULT_SIZE: usize = 200;
+
+    /// A terminal session that explores every directory once, depth first.
+    ///
+    /// Half of all directories go straight under the previous one, so trees get deep. The disk
+    /// ends up too full for the update, so part 2 always has something to free.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let dirs = size.max(1);
+        let mut children = vec![Vec::new(); dirs];
+        for dir in 1..dirs {
+            let parent = if rng.gen_bool(0.5) {
+                dir - 1
+            } else {
+                rng.gen_range(0..dir)
+            };
+            children[parent].push(dir);
+        }
+
+        let mut files: Vec<Vec<u64>> = (0..dirs)
+            .map(|_| {
+                (0..rng.gen_range(0..=4))
+                    .map(|_| rng.gen_range(1..=100))
+                    .collect()
+            })
+            .collect();
+        files[0].push(rng.gen_range(1..=100));
+        let weight: u64 = files.iter().flatten().sum();
+        let used = rng.gen_range(TOTAL_SPACE * 6 / 10..=TOTAL_SPACE - NEEDED_SPACE / 10);
+        files
+            .iter_mut()
+            .flatten()
+            .for_each(|size| *size = (*size * used / weight).max(1));
+
+        let mut session = String::new();
+        // Directories still to visit, or `None` to go back up a level.
+        let mut to_visit = vec![Some(0)];
+        while let Some(next) = to_visit.pop() {
+            let Some(dir) = next else {
+                session.push_str("$ cd ..\n");
+                continue;
+            };
+            if dir == 0 {
+                session.push_str("$ cd /\n");
+            } else {
+                session.push_str(&format!("$ cd {}\n", name(dir)));
+            }
+            session.push_str("$ ls\n");
+            let mut entries: Vec<String> = children[dir]
+                .iter()
+                .map(|&child| format!("dir {}\n", name(child)))
+                .chain(
+                    files[dir]
+                        .iter()
+                        .enumerate()
+                        .map(|(i, size)| format!("{} {}.dat\n", size, name(i))),
+                )
+                .collect();
+            entries.shuffle(rng);
+            entries.iter().for_each(|entry| session.push_str(entry));
+            for &child in children[dir].iter().rev() {
+                to_visit.push(None);
+                to_visit.push(Some(child));
+            }
+        }
+        session
+    }
+}
+
+/// A unique lowercase name for each index: `a` to `z`, then `aa`, `ab` and so on.
+fn name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(char::from(b'a' + (index % 26) as u8));
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    name.into_iter().rev().collect()
+}
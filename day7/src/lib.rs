@@ -0,0 +1,352 @@
+use std::{cell::Cell, collections::HashMap};
+
+use common_utils::{parse_lines, Answer, AocError, AocResult, ParseDiagnostic, Solution};
+use itertools::Itertools;
+
+#[cfg(feature = "generator")]
+pub mod generator;
+
+pub struct Day7;
+
+/// Size of the disk, in bytes.
+pub const TOTAL_SPACE: u64 = 70000000;
+/// Free space the update needs, in bytes.
+pub const NEEDED_SPACE: u64 = 30000000;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Parsed = Dir;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        let mut builder = TreeBuilder::new();
+        builder.parse(parse_lines(input, parser::line))?;
+        Ok(builder.build())
+    }
+
+    fn part1(root: &Self::Parsed) -> AocResult<Answer> {
+        Ok(root.get_sum_size_under_threshold(100000).into())
+    }
+
+    fn part2(root: &Self::Parsed) -> AocResult<Answer> {
+        let currently_free = TOTAL_SPACE
+            .checked_sub(root.get_recursive_size())
+            .ok_or_else(|| AocError::invalid_state("The files don't fit on the disk"))?;
+        let to_free = NEEDED_SPACE.saturating_sub(currently_free);
+        root.get_min_size_over_threshold(to_free)
+            .map(Into::into)
+            .ok_or_else(|| {
+                AocError::no_solution(format!("No directory frees up {} bytes", to_free))
+            })
+    }
+}
+
+#[derive(Debug)]
+struct TreeBuilder {
+    /// Indices into `dirs` from the root down to the working directory; never empty.
+    pwd: Vec<usize>,
+    dirs: Vec<DirBuilder>,
+    /// Every file's size added up. No directory holds more than this, so once it fits, so does
+    /// every [`Dir::get_recursive_size`].
+    total_size: u64,
+}
+
+impl TreeBuilder {
+    fn new() -> Self {
+        Self {
+            pwd: vec![0],
+            dirs: vec![DirBuilder::new("/".to_owned())],
+            total_size: 0,
+        }
+    }
+
+    fn parse(
+        &mut self,
+        mut lines: impl Iterator<Item = Result<Line, ParseDiagnostic>>,
+    ) -> AocResult<()> {
+        lines.try_for_each(|line_res| {
+            let line = line_res?;
+            tracing::debug!(?line, "terminal line");
+            match line {
+                Line::Command(c) => match c {
+                    Command::Dir(spec) => match spec {
+                        DirSpec::Root => self.cd_root(),
+                        DirSpec::Up => self.cd_up()?,
+                        DirSpec::Down(name) => self.cd_down(name)?,
+                    },
+                    Command::Ls => {}
+                },
+                Line::Entry(e) => match e {
+                    DirOrFileEntry::File(size) => {
+                        self.total_size = self.total_size.checked_add(size).ok_or_else(|| {
+                            AocError::invalid_state("The files add up to more than a u64 holds")
+                        })?;
+                        self.pwd_mut().add_file(size)?;
+                    }
+                    DirOrFileEntry::Dir(name) => {
+                        let child_idx = self.dirs.len();
+                        self.pwd_mut().add_subdir(name.clone(), child_idx);
+                        self.dirs.push(DirBuilder::new(name));
+                    }
+                },
+            }
+            Ok(())
+        })
+    }
+
+    fn pwd_mut(&mut self) -> &mut DirBuilder {
+        let idx = self.pwd.last().copied().unwrap_or(0);
+        &mut self.dirs[idx]
+    }
+
+    fn cd_root(&mut self) {
+        while self.pwd.len() > 1 {
+            self.pwd.pop();
+        }
+    }
+
+    fn cd_up(&mut self) -> AocResult<()> {
+        if self.pwd.len() == 1 {
+            return Err(AocError::invalid_state("Can't cd .. out of /"));
+        }
+        self.pwd.pop();
+        Ok(())
+    }
+
+    fn cd_down(&mut self, dir_name: String) -> AocResult<()> {
+        let pwd = self.pwd_mut();
+        let next_idx = *pwd.children.get(&dir_name).ok_or_else(|| {
+            AocError::invalid_state(format!(
+                "Couldn't find a dir named {} under {}",
+                dir_name, pwd.name
+            ))
+        })?;
+        self.pwd.push(next_idx);
+        Ok(())
+    }
+
+    fn build(self) -> Dir {
+        let root = &self.dirs[0];
+        root.build(&self.dirs)
+    }
+}
+
+#[derive(Debug)]
+struct DirBuilder {
+    pub name: String,
+    pub self_size: u64,
+    pub children: HashMap<String, usize>,
+}
+
+impl DirBuilder {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            self_size: 0,
+            children: HashMap::new(),
+        }
+    }
+
+    fn add_file(&mut self, size: u64) -> AocResult<()> {
+        self.self_size = self.self_size.checked_add(size).ok_or_else(|| {
+            AocError::invalid_state(format!(
+                "The files in {} add up to more than a u64 holds",
+                self.name
+            ))
+        })?;
+        Ok(())
+    }
+
+    fn add_subdir(&mut self, subdir_name: String, subdir_idx: usize) {
+        self.children.insert(subdir_name, subdir_idx);
+    }
+
+    fn build(&self, other_builders: &[DirBuilder]) -> Dir {
+        let children = self
+            .children
+            .values()
+            .copied()
+            .map(|idx| &other_builders[idx])
+            .map(|builder| builder.build(other_builders))
+            .collect_vec();
+        Dir::new(self.name.clone(), self.self_size, children)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Dir {
+    #[allow(dead_code)]
+    pub name: String,
+    pub self_size: u64,
+    recursive_size: Cell<Option<u64>>,
+    pub children: Vec<Dir>,
+}
+
+impl Dir {
+    pub fn new(name: String, self_size: u64, children: Vec<Dir>) -> Self {
+        Self {
+            name,
+            self_size,
+            recursive_size: Cell::new(None),
+            children,
+        }
+    }
+
+    pub fn get_recursive_size(&self) -> u64 {
+        if let Some(size) = self.recursive_size.get() {
+            size
+        } else {
+            let size = self.self_size
+                + self
+                    .children
+                    .iter()
+                    .map(Dir::get_recursive_size)
+                    .sum::<u64>();
+            self.recursive_size.set(Some(size));
+            size
+        }
+    }
+
+    pub fn get_sum_size_under_threshold(&self, threshold: u64) -> u64 {
+        let child_sizes = self
+            .children
+            .iter()
+            .map(|child| child.get_sum_size_under_threshold(threshold))
+            .sum::<u64>();
+        child_sizes
+            + if self.get_recursive_size() <= threshold {
+                self.get_recursive_size()
+            } else {
+                0
+            }
+    }
+
+    pub fn get_min_size_over_threshold(&self, threshold: u64) -> Option<u64> {
+        self.children
+            .iter()
+            .filter_map(|child| child.get_min_size_over_threshold(threshold))
+            .min()
+            .or_else(|| {
+                let own_size = self.get_recursive_size();
+                if own_size >= threshold {
+                    Some(own_size)
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Line {
+    Command(Command),
+    Entry(DirOrFileEntry),
+}
+
+impl From<Command> for Line {
+    fn from(val: Command) -> Self {
+        Line::Command(val)
+    }
+}
+
+impl From<DirOrFileEntry> for Line {
+    fn from(val: DirOrFileEntry) -> Self {
+        Line::Entry(val)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Command {
+    Ls,
+    Dir(DirSpec),
+}
+
+impl From<DirSpec> for Command {
+    fn from(val: DirSpec) -> Self {
+        Command::Dir(val)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum DirSpec {
+    Up,
+    Root,
+    Down(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum DirOrFileEntry {
+    Dir(String),
+    File(u64),
+}
+
+pub mod parser {
+    use super::*;
+
+    use common_utils::ParseResult;
+    use nom::{
+        branch::alt,
+        bytes::complete::{tag, take_while},
+        character::complete::u64 as nom_u64,
+        combinator::map,
+        error::context,
+        sequence::{preceded, separated_pair},
+    };
+
+    pub fn line(s: &str) -> ParseResult<'_, Line> {
+        context(
+            "command or directory entry",
+            alt((map(command, Into::into), map(entry, Into::into))),
+        )(s)
+    }
+
+    pub fn command(s: &str) -> ParseResult<'_, Command> {
+        preceded(
+            tag("$ "),
+            alt((
+                map(tag("ls"), |_| Command::Ls),
+                map(preceded(tag("cd "), dir_spec), Into::into),
+            )),
+        )(s)
+    }
+
+    pub fn dir_spec(s: &str) -> ParseResult<'_, DirSpec> {
+        alt((
+            map(tag(".."), |_| DirSpec::Up),
+            map(tag("/"), |_| DirSpec::Root),
+            map(take_while(|_| true), |name: &str| {
+                DirSpec::Down(name.to_owned())
+            }),
+        ))(s)
+    }
+
+    pub fn entry(s: &str) -> ParseResult<'_, DirOrFileEntry> {
+        alt((
+            map(preceded(tag("dir "), take_while(|_| true)), |name: &str| {
+                DirOrFileEntry::Dir(name.to_owned())
+            }),
+            map(
+                separated_pair(nom_u64, tag(" "), take_while(|_| true)),
+                |(size, _)| DirOrFileEntry::File(size),
+            ),
+        ))(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oversized_files_are_an_error() {
+        let listing = format!(
+            "$ cd /\n$ ls\ndir a\n{} big\n$ cd a\n$ ls\n{} bigger\n",
+            u64::MAX,
+            u64::MAX
+        );
+        assert!(matches!(
+            Day7::parse(&listing),
+            Err(AocError::InvalidState(_))
+        ));
+    }
+}
@@ -0,0 +1,21 @@
+use common_utils::Generator;
+use rand::Rng;
+
+use crate::Day8;
+
+impl Generator for Day8 {
+    const SIZE_UNIT: &'static str = "rows and columns";
+    const DEFAULT_SIZE: usize = 99;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        let side = size.max(1);
+        (0..side)
+            .map(|_| {
+                (0..side)
+                    .map(|_| char::from(rng.gen_range(b'0'..=b'9')))
+                    .chain(Some('\n'))
+                    .collect::<String>()
+            })
+            .collect()
+    }
+}
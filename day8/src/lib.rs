@@ -0,0 +1,137 @@
+use std::ops::ControlFlow;
+
+use arrayvec::ArrayVec;
+use common_utils::{Answer, AocResult, Grid, Solution};
+
+#[cfg(feature = "generator")]
+pub mod generator;
+#[cfg(feature = "render")]
+pub mod render;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        Grid::parse(input, |_, c| match c.to_digit(10) {
+            Some(height) => Ok(height as u8),
+            None => Err("a tree height from 0 to 9"),
+        })
+    }
+
+    fn part1(grid: &Self::Parsed) -> AocResult<Answer> {
+        Ok(count_visible(grid).into())
+    }
+
+    fn part2(grid: &Self::Parsed) -> AocResult<Answer> {
+        Ok(compute_max_scenic_score(grid).into())
+    }
+}
+
+pub fn count_visible(grid: &Grid<u8>) -> usize {
+    visibility(grid).iter().filter(|&&x| x).count()
+}
+
+/// Which trees can be seen from outside the grid.
+pub fn visibility(grid: &Grid<u8>) -> Grid<bool> {
+    let num_rows = grid.rows();
+    let num_cols = grid.cols();
+    let mut visible = Grid::new(num_rows, num_cols, false);
+    for i in 0..num_rows {
+        let row_visibility = visible.row_mut(i);
+        row_visibility[0] = true;
+        row_visibility[num_cols - 1] = true;
+    }
+    visible.row_mut(0).fill(true);
+    visible.row_mut(num_rows - 1).fill(true);
+
+    for i in 0..num_rows {
+        let line = grid.row(i);
+        set_visibility(line.iter().copied(), visible.row_mut(i).iter_mut());
+        set_visibility(
+            line.iter().rev().copied(),
+            visible.row_mut(i).iter_mut().rev(),
+        );
+    }
+    for i in 0..num_cols {
+        set_visibility(grid.column(i).copied(), visible.column_mut(i));
+        set_visibility(grid.column(i).rev().copied(), visible.column_mut(i).rev());
+    }
+
+    visible
+}
+
+pub fn set_visibility<'a>(
+    line: impl Iterator<Item = u8>,
+    visibilities: impl Iterator<Item = &'a mut bool>,
+) {
+    let _ = line
+        .zip(visibilities)
+        .try_fold(0, |max_height, (height, visibility)| {
+            if height > max_height {
+                *visibility = true;
+                if height == 9 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(height)
+                }
+            } else {
+                ControlFlow::Continue(max_height)
+            }
+        });
+}
+
+pub fn compute_max_scenic_score(grid: &Grid<u8>) -> u32 {
+    scenic_scores(grid).iter().copied().max().unwrap_or(0)
+}
+
+/// Every tree's scenic score.
+pub fn scenic_scores(grid: &Grid<u8>) -> Grid<u32> {
+    let mut products = Grid::new(grid.rows(), grid.cols(), 1u32);
+    for i in 0..grid.rows() {
+        let grid_row = grid.row(i);
+        products
+            .row_mut(i)
+            .iter_mut()
+            .zip(compute_scenic_score_line(grid_row.iter().copied()))
+            .for_each(|(elem, new_score)| *elem *= new_score);
+        products
+            .row_mut(i)
+            .iter_mut()
+            .rev()
+            .zip(compute_scenic_score_line(grid_row.iter().rev().copied()))
+            .for_each(|(elem, new_score)| *elem *= new_score);
+    }
+    for i in 0..grid.cols() {
+        products
+            .column_mut(i)
+            .zip(compute_scenic_score_line(grid.column(i).copied()))
+            .for_each(|(elem, new_score)| *elem *= new_score);
+        products
+            .column_mut(i)
+            .rev()
+            .zip(compute_scenic_score_line(grid.column(i).rev().copied()))
+            .for_each(|(elem, new_score)| *elem *= new_score);
+    }
+
+    products
+}
+
+pub fn compute_scenic_score_line(line: impl Iterator<Item = u8>) -> impl Iterator<Item = u32> {
+    line.enumerate().scan(
+        ArrayVec::<(usize, u8), 10>::new(),
+        |previous_maxes, (i, height)| {
+            previous_maxes.retain(|(_, previous_max)| *previous_max >= height);
+            let previous_max = *previous_maxes.last().unwrap_or(&(0, 0));
+            let score = (i - previous_max.0) as u32;
+            if previous_max.1 == height {
+                previous_maxes.pop();
+            }
+            previous_maxes.push((i, height));
+            Some(score)
+        },
+    )
+}
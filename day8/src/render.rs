@@ -0,0 +1,46 @@
+use common_utils::{AocResult, Grid, Part, Picture, Render, Rgb};
+
+use crate::{scenic_scores, visibility, Day8};
+
+impl Render for Day8 {
+    /// Part 1 shades each tree by height, bright if it's visible from outside and dark if not.
+    /// Part 2 is a heat map of scenic scores, with the best tree in red.
+    fn picture(grid: &Self::Parsed, part: Part) -> AocResult<Picture> {
+        Ok(match part {
+            Part::One => visibility_map(grid),
+            Part::Two => scenic_map(grid),
+        })
+    }
+}
+
+fn visibility_map(grid: &Grid<u8>) -> Picture {
+    let visible = visibility(grid);
+    let hidden_colours = (0..10).map(|height| [height * 6, 40 + height * 8, height * 4]);
+    let visible_colours = (0..10).map(|height| [150 + height * 10, 200 + height * 5, 40]);
+    let palette = hidden_colours.chain(visible_colours).collect();
+    Picture::from_grid(grid, palette, |position, &height| {
+        height + if visible[position] { 10 } else { 0 }
+    })
+}
+
+/// Scores span orders of magnitude, so the heat map goes by their logarithm.
+fn scenic_map(grid: &Grid<u8>) -> Picture {
+    const LEVELS: u8 = 16;
+    let scores = scenic_scores(grid);
+    let best = scores.iter().copied().max().unwrap_or(0);
+    let top = f64::from(best).ln_1p().max(f64::MIN_POSITIVE);
+    let mut palette: Vec<Rgb> = (0..LEVELS)
+        .map(|level| {
+            let heat = level as u32 * 255 / (LEVELS as u32 - 1);
+            [heat as u8, (heat * 3 / 4) as u8, (255 - heat) as u8 / 2]
+        })
+        .collect();
+    palette.push([230, 20, 20]);
+    Picture::from_grid(&scores, palette, |_, &score| {
+        if score == best {
+            LEVELS
+        } else {
+            (f64::from(score).ln_1p() / top * f64::from(LEVELS - 1)) as u8
+        }
+    })
+}
@@ -0,0 +1,22 @@
+//! Checks the one-pass scenic score scan against looking back from every tree in turn.
+
+use day8::compute_scenic_score_line;
+use proptest::prelude::*;
+
+/// How many trees each tree can see towards the start of the line.
+fn naive_scores(line: &[u8]) -> Vec<u32> {
+    (0..line.len())
+        .map(|i| {
+            let blocker = (0..i).rev().find(|&j| line[j] >= line[i]);
+            (i - blocker.unwrap_or(0)) as u32
+        })
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn scenic_scores_match_naive(line in prop::collection::vec(0u8..=9, 0..64)) {
+        let fast: Vec<u32> = compute_scenic_score_line(line.iter().copied()).collect();
+        prop_assert_eq!(fast, naive_scores(&line));
+    }
+}
@@ -0,0 +1,18 @@
+use common_utils::Generator;
+use rand::Rng;
+
+use crate::Day9;
+
+impl Generator for Day9 {
+    const SIZE_UNIT: &'static str = "moves";
+    const DEFAULT_SIZE: usize = 2000;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let dir = ['U', 'D', 'L', 'R'][rng.gen_range(0..4)];
+                format!("{} {}\n", dir, rng.gen_range(1..=19))
+            })
+            .collect()
+    }
+}
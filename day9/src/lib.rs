@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+
+use common_utils::{
+    parse_lines, Answer, AocError, AocResult, BoundingBox, Direction4, Point2, Solution,
+};
+
+#[cfg(feature = "generator")]
+pub mod generator;
+#[cfg(feature = "visualize")]
+pub mod visualize;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<Move>;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        let moves: Vec<Move> = parse_lines(input, parse::parse_move).collect::<Result<_, _>>()?;
+        // The knots can't leave the head's bounds, so this keeps all of them in range.
+        head_bounds(&moves)?;
+        Ok(moves)
+    }
+
+    fn part1(moves: &Self::Parsed) -> AocResult<Answer> {
+        Ok(get_distinct_spaces::<2>(moves.iter().copied()).into())
+    }
+
+    fn part2(moves: &Self::Parsed) -> AocResult<Answer> {
+        Ok(get_distinct_spaces::<10>(moves.iter().copied()).into())
+    }
+}
+
+/// Everywhere the head goes. The other knots never leave it.
+///
+/// An error if the head gets further from the start than an `i32` reaches.
+pub fn head_bounds(moves: &[Move]) -> AocResult<BoundingBox<i32>> {
+    let mut head = Point2::<i32>::ORIGIN;
+    let mut bounds = BoundingBox {
+        min: head,
+        max: head,
+    };
+    for (i, mov) in moves.iter().enumerate() {
+        let offset: Point2<i32> = mov.dir.offset();
+        let steps = i32::from(mov.steps);
+        head = head
+            .x
+            .checked_add(offset.x * steps)
+            .zip(head.y.checked_add(offset.y * steps))
+            .map(|(x, y)| Point2::new(x, y))
+            .ok_or_else(|| {
+                AocError::invalid_state(format!("Move {} takes the head out of range", i + 1))
+            })?;
+        bounds.include(head);
+    }
+    Ok(bounds)
+}
+
+/// How many positions the tail visits. The moves have to stay within [`head_bounds`].
+pub fn get_distinct_spaces<const KNOTS: usize>(iter: impl Iterator<Item = Move>) -> usize {
+    let mut visited_spaces: HashSet<Point2<i32>> = HashSet::new();
+    visited_spaces.insert(Point2::ORIGIN);
+    iter.fold([Point2::ORIGIN; KNOTS], |mut knots, mov| {
+        let Move { dir, steps } = mov;
+        for _ in 0..steps {
+            step_rope(&mut knots, dir);
+            tracing::debug!(head = ?knots[0], tail = ?knots[KNOTS - 1], "rope step");
+            visited_spaces.insert(knots[KNOTS - 1]);
+        }
+        knots
+    });
+    visited_spaces.len()
+}
+
+/// Moves the head one step in `dir`, then each knot after it follows the one in front.
+pub fn step_rope(knots: &mut [Point2<i32>], dir: Direction4) {
+    knots[0] += dir.offset();
+    for i in 0..knots.len() - 1 {
+        if knots[i].chebyshev(knots[i + 1]) > 1 {
+            knots[i + 1] = move_towards_head(knots[i], knots[i + 1]);
+        } else {
+            break;
+        }
+    }
+}
+
+pub fn move_towards_head(head: Point2<i32>, tail: Point2<i32>) -> Point2<i32> {
+    let delta = head - tail;
+    tail + Point2::new(delta.x.signum(), delta.y.signum())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Move {
+    pub dir: Direction4,
+    pub steps: u16,
+}
+
+pub mod parse {
+    use super::*;
+
+    use common_utils::ParseResult;
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::u16 as nom_u16,
+        combinator::{map, value},
+        error::context,
+        sequence::separated_pair,
+    };
+
+    pub fn direction(s: &str) -> ParseResult<'_, Direction4> {
+        context(
+            "direction (U, D, L or R)",
+            alt((
+                value(Direction4::Up, tag("U")),
+                value(Direction4::Down, tag("D")),
+                value(Direction4::Left, tag("L")),
+                value(Direction4::Right, tag("R")),
+            )),
+        )(s)
+    }
+
+    pub fn parse_move(s: &str) -> ParseResult<'_, Move> {
+        map(
+            separated_pair(direction, tag(" "), context("step count", nom_u16)),
+            |(dir, steps)| Move { dir, steps },
+        )(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() -> AocResult<()> {
+        static SAMPLE: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2";
+        let moves = Day9::parse(SAMPLE)?;
+        assert_eq!(13, get_distinct_spaces::<2>(moves.iter().copied()));
+        assert_eq!(1, get_distinct_spaces::<10>(moves.iter().copied()));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_heads_out_of_range() {
+        // One move more than fits in an i32.
+        let far = "R 65535\n".repeat((1 << 15) + 1);
+        assert!(matches!(Day9::parse(&far), Err(AocError::InvalidState(_))));
+        let back_and_forth = "R 65535\nL 65535\n".repeat(1 << 15);
+        assert_eq!(
+            Day9::parse(&back_and_forth).map(|moves| moves.len()).ok(),
+            Some(1 << 16)
+        );
+    }
+}
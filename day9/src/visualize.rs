@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+use std::iter;
+
+use common_utils::{AocResult, BoundingBox, Frame, Part, Point2, Visualize};
+
+use crate::{head_bounds, step_rope, Day9};
+
+impl Visualize for Day9 {
+    const FPS: u32 = 60;
+
+    /// The rope after every single step, over the cells its tail has visited, following the
+    /// head around.
+    fn frames(
+        moves: &Self::Parsed,
+        part: Part,
+    ) -> AocResult<impl Iterator<Item = AocResult<Frame>> + '_> {
+        let knot_count = match part {
+            Part::One => 2,
+            Part::Two => 10,
+        };
+        let bounds = head_bounds(moves)?;
+        let mut knots = vec![Point2::ORIGIN; knot_count];
+        let mut visited = HashSet::from([Point2::ORIGIN]);
+        let start = draw_rope(&bounds, &knots, &visited, "start".to_owned());
+        let steps = moves
+            .iter()
+            .enumerate()
+            .flat_map(|(i, mov)| iter::repeat_n((i, mov), mov.steps as usize));
+        let frames = steps.map(move |(i, mov)| {
+            step_rope(&mut knots, mov.dir);
+            visited.extend(knots.last().copied());
+            let caption = format!(
+                "{:?} {} (move {}/{}), tail visited {}",
+                mov.dir,
+                mov.steps,
+                i + 1,
+                moves.len(),
+                visited.len()
+            );
+            Ok(draw_rope(&bounds, &knots, &visited, caption))
+        });
+        Ok(iter::once(Ok(start)).chain(frames))
+    }
+}
+
+/// `H` for the head, then the knots numbered (or `T` for a two-knot tail), over `#` where the
+/// tail has been and `s` at the start.
+fn draw_rope(
+    bounds: &BoundingBox<i32>,
+    knots: &[Point2<i32>],
+    visited: &HashSet<Point2<i32>>,
+    caption: String,
+) -> Frame {
+    // Differences of two i32s always fit in a u32, even where `width` and `height` wouldn't.
+    let to_position = |point: Point2<i32>| {
+        (
+            point.y.abs_diff(bounds.min.y) as usize,
+            point.x.abs_diff(bounds.min.x) as usize,
+        )
+    };
+    let (rows, cols) = to_position(bounds.max);
+    let mut cells = vec![vec![b'.'; cols + 1]; rows + 1];
+    let mut mark = |point, label| {
+        let (row, col) = to_position(point);
+        cells[row][col] = label;
+    };
+    visited.iter().for_each(|&point| mark(point, b'#'));
+    mark(Point2::ORIGIN, b's');
+    // Knots in front cover the ones behind.
+    for (i, &knot) in knots.iter().enumerate().rev() {
+        let label = match i {
+            0 => b'H',
+            _ if knots.len() == 2 => b'T',
+            _ => b'0' + i as u8,
+        };
+        mark(knot, label);
+    }
+    let rows = cells
+        .into_iter()
+        .map(|row| String::from_utf8_lossy(&row).into_owned())
+        .collect();
+    Frame::new(caption, rows).with_focus(to_position(knots[0]))
+}